@@ -0,0 +1,43 @@
+//! Compares `is_square` against the naive round trip through `sqrt`.
+//!
+//! Run with `cargo bench`.
+
+extern crate int_traits;
+
+use int_traits::IntSqrt;
+use std::hint::black_box;
+use std::time::Instant;
+
+const ITERATIONS: u64 = 10_000_000;
+
+fn naive(n: u64) -> bool {
+    let root = n.sqrt();
+    root * root == n
+}
+
+fn bench<F: Fn(u64) -> bool>(name: &str, f: F) {
+    // Consecutive values starting near 2^62, as seen by a Fermat search.
+    let start = 1_u64 << 62;
+    let now = Instant::now();
+    let mut count = 0;
+    for n in start..start + ITERATIONS {
+        if f(black_box(n)) {
+            count += 1;
+        }
+    }
+    // Hundredths of a nanosecond, kept in integers like the crate itself.
+    let per_iter = now.elapsed().as_nanos() * 100 / ITERATIONS as u128;
+
+    println!(
+        "{:<10} {:>5}.{:02} ns/iter ({} squares)",
+        name,
+        per_iter / 100,
+        per_iter % 100,
+        count
+    );
+}
+
+fn main() {
+    bench("naive", naive);
+    bench("is_square", |n| n.is_square());
+}
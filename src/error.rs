@@ -0,0 +1,54 @@
+//! The error type returned by the `try_` functions of `IntTraits`.
+
+use core::fmt;
+
+/// The reason an integer function could not produce a result.
+///
+/// Where it is meaningful the offending input is carried along with the
+/// error.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IntTraitsError<T> {
+    /// The input was negative where only non-negative values are accepted.
+    NegativeInput(T),
+
+    /// The input was zero where only positive values are accepted.
+    ZeroInput,
+
+    /// The base of a logarithm was less than 2.
+    InvalidBase(u64),
+
+    /// The degree of a root was zero.
+    InvalidDegree(u32),
+
+    /// The result does not fit in the type.
+    Overflow(T),
+}
+
+impl<T> IntTraitsError<T> {
+    /// Converts the carried value, used when an implementation defers to that
+    /// of another integer type.
+    pub(crate) fn map<U, F: FnOnce(T) -> U>(self, f: F) -> IntTraitsError<U> {
+        match self {
+            IntTraitsError::NegativeInput(n) => IntTraitsError::NegativeInput(f(n)),
+            IntTraitsError::ZeroInput => IntTraitsError::ZeroInput,
+            IntTraitsError::InvalidBase(base) => IntTraitsError::InvalidBase(base),
+            IntTraitsError::InvalidDegree(k) => IntTraitsError::InvalidDegree(k),
+            IntTraitsError::Overflow(n) => IntTraitsError::Overflow(f(n)),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for IntTraitsError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            IntTraitsError::NegativeInput(ref n) => write!(f, "input is negative: {}", n),
+            IntTraitsError::ZeroInput => write!(f, "input is zero"),
+            IntTraitsError::InvalidBase(base) => write!(f, "base is less than 2: {}", base),
+            IntTraitsError::InvalidDegree(k) => write!(f, "root degree must be positive: {}", k),
+            IntTraitsError::Overflow(ref n) => write!(f, "result overflows for input: {}", n),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: fmt::Debug + fmt::Display> ::std::error::Error for IntTraitsError<T> {}
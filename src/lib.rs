@@ -2,145 +2,2471 @@
 //! integer type. These are typically special cases such as `sqrt` and are aimed
 //! primarily at reducing the incessant casting that is otherwise required for
 //! floored integer behaviour.
+//!
+//! Every function is implemented with integer arithmetic alone. Results are
+//! exact for every value of every type, and no floating point routines are
+//! pulled in, which matters on targets without an FPU where they would be
+//! emulated in software.
+//!
+//! The crate is `no_std`. The default `std` feature only adds an
+//! implementation of `std::error::Error` for `IntTraitsError`.
+
+#![no_std]
+#![forbid(unsafe_code)]
+// Guard against a floating point shortcut creeping back in. Naming `f32` or
+// `f64` anywhere, including in a call such as `f64::sqrt` or a cast, is
+// rejected through `disallowed-types` in `clippy.toml`.
+#![deny(clippy::float_arithmetic, clippy::cast_precision_loss, clippy::disallowed_types)]
+
+#[cfg(test)]
+#[macro_use]
+extern crate std;
+#[cfg(all(feature = "std", not(test)))]
+extern crate std;
+
+use core::convert::TryFrom;
+
+mod error;
+mod nonzero;
+mod primitive;
+mod traits;
+mod wrapper;
+
+pub use error::IntTraitsError;
+pub use nonzero::NonZeroIntTraits;
+pub use primitive::PrimitiveInt;
+pub use traits::{IntCbrt, IntGcd, IntLog, IntModular, IntPrime, IntRoot, IntSqrt};
 
 /// Provides functions which extended the class methods on integers.
-pub trait IntTraits<T: Sized> where Self: Sized {
-    /// Takes the floored square root of a number.
-    ///
-    /// ## Panics
-    /// Panics if `n` is negative.
-    fn sqrt(self) -> T;
-
-    /// Takes the floored cubic root of a number.
-    ///
-    /// ## Panics
-    /// Panics if `n` is negative.
-    fn cbrt(self) -> T;
-
-    /// Returns the floored logarithm of `n`.
-    ///
-    /// The logarithm must be of integer base. This is to avoid unnecessary
-    /// casts and is purely ergonomic.
-    ///
-    /// ## Panics
-    /// Panics if `n` <= 0.
-    fn log(self, n: u64) -> T;
-
-    /// Returns the floored base 10 logarithm of `n`.
-    ///
-    /// ## Panics
-    /// Panics if `n` <= 0.
-    fn log10(self) -> T {
-        self.log(10 as u64)
-    }
-
-    /// Returns the floored base 10 logarithm of `n`.
-    ///
-    /// ## Panics
-    /// Panics if `n` <= 0.
-    fn log2(self) -> T {
-        self.log(2 as u64)
-    }
+///
+/// This combines `IntSqrt`, `IntCbrt`, `IntRoot`, `IntLog`, `IntGcd`,
+/// `IntModular` and `IntPrime` and is implemented for every type implementing
+/// all of them, so a single `T: IntTraits<T>` bound gives generic code every
+/// function.
+pub trait IntTraits<T = Self>:
+    IntSqrt<Output = T>
+    + IntCbrt<Output = T>
+    + IntRoot<Output = T>
+    + IntLog<Output = T>
+    + IntGcd<Output = T>
+    + IntModular<Output = T>
+    + IntPrime
+{
 }
 
-macro_rules! impl_int_trait {
-    ($t:ty) => {
-        impl IntTraits<$t> for $t {
-            fn sqrt(self) -> $t {
-                if self < 0 {
-                    panic!("cannot take sqrt of a negative value: {}", self)
+impl<N, T> IntTraits<T> for N where
+    N: IntSqrt<Output = T>
+        + IntCbrt<Output = T>
+        + IntRoot<Output = T>
+        + IntLog<Output = T>
+        + IntGcd<Output = T>
+        + IntModular<Output = T>
+        + IntPrime
+{
+}
+
+// Bitmaps of the quadratic residues modulo 64, 63, 65 and 11, with bit `i`
+// set if `i` is a square modulo `m`. A value whose residue is not set cannot
+// be a square, and only around 1 in 120 non-squares passes all four.
+//
+// The last three moduli divide 45045, so a single reduction by it leaves a
+// residue small enough for the remaining reductions to be done in a `u32`.
+const SQUARES_MOD_64: u64 = 0x0202_0212_0203_0213;
+const SQUARES_MOD_63: u64 = 0x0402_4830_1245_0293;
+const SQUARES_MOD_65: u128 = 0x1_218a_0198_6601_4613;
+const SQUARES_MOD_11: u16 = 0x23b;
+const SQUARES_MODULUS: u32 = 63 * 65 * 11;
+
+// Bitmaps of the cubic residues modulo 63 and 13.
+const CUBES_MOD_63: u64 = 0x4080_0018_1800_0103;
+const CUBES_MOD_13: u16 = 0x1123;
+
+macro_rules! impl_uint_consts {
+    ($t:ident) => {
+        #[doc = concat!("Constant functions on `", stringify!($t), "`.")]
+        ///
+        /// These are the algorithms behind `IntTraits`, exposed as `const fn`
+        /// so they can be evaluated at compile time, such as for array
+        /// lengths. A constant function cannot format its panic message, so
+        /// these panic with a fixed message on invalid input.
+        pub mod $t {
+            /// Takes the floored square root `r` of `n` along with the
+            /// remainder `n - r^2`.
+            pub const fn sqrt_rem(n: $t) -> ($t, $t) {
+                // Digit-by-digit method, consuming two bits of the input per
+                // step. Unlike a round trip through `f64` this is exact for
+                // every value of the type, and what is left of the input once
+                // all bits are consumed is the remainder.
+                let mut n = n;
+                let mut root = 0;
+                let mut bit: $t = 1 << (<$t>::BITS - 2);
+
+                while bit > n {
+                    bit >>= 2;
+                }
+
+                while bit != 0 {
+                    if n >= root + bit {
+                        n -= root + bit;
+                        root = (root >> 1) + bit;
+                    } else {
+                        root >>= 1;
+                    }
+                    bit >>= 2;
                 }
-                (self as f64).sqrt() as $t
+
+                (root, n)
             }
 
-            fn cbrt(self) -> $t {
-                if self < 0 {
-                    panic!("cannot take cbrt of a negative value: {}", self)
+            /// Takes the floored square root of `n`.
+            pub const fn sqrt(n: $t) -> $t {
+                sqrt_rem(n).0
+            }
+
+            /// Takes the ceiling of the square root of `n`.
+            pub const fn sqrt_ceil(n: $t) -> $t {
+                // The floored root of the maximum is below the square root of
+                // the type's range, so the increment cannot overflow.
+                let (root, rem) = sqrt_rem(n);
+                if rem == 0 { root } else { root + 1 }
+            }
+
+            /// Takes the floored cubic root `r` of `n` along with the
+            /// remainder `n - r^3`.
+            pub const fn cbrt_rem(n: $t) -> ($t, $t) {
+                // Bitwise method, consuming three bits of the input per step.
+                // The comparison is made against the shifted input so that the
+                // trial subtrahend never overflows.
+                let mut n = n;
+                let mut root: $t = 0;
+                let mut shift = (<$t>::BITS - 1) / 3 * 3;
+
+                loop {
+                    root <<= 1;
+                    let b = 3 * root * (root + 1) + 1;
+                    if n >> shift >= b {
+                        n -= b << shift;
+                        root += 1;
+                    }
+
+                    if shift == 0 {
+                        break;
+                    }
+                    shift -= 3;
+                }
+
+                (root, n)
+            }
+
+            /// Takes the floored cubic root of `n`.
+            pub const fn cbrt(n: $t) -> $t {
+                cbrt_rem(n).0
+            }
+
+            /// Takes the ceiling of the cubic root of `n`.
+            pub const fn cbrt_ceil(n: $t) -> $t {
+                let (root, rem) = cbrt_rem(n);
+                if rem == 0 { root } else { root + 1 }
+            }
+
+            /// Takes the floored `k`-th root of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `k` is zero.
+            pub const fn nth_root(n: $t, k: u32) -> $t {
+                match k {
+                    0 => panic!("root degree must be positive"),
+                    1 => return n,
+                    2 => return sqrt(n),
+                    3 => return cbrt(n),
+                    _ => {}
                 }
-                (self as f64).cbrt() as $t
+
+                // The root has at most `BITS / k` bits, rounded up. Each is
+                // set from the most significant down and kept only if the
+                // trial power does not exceed the input.
+                let mut root: $t = 0;
+                let mut bit = (<$t>::BITS - 1) / k;
+
+                loop {
+                    let trial = root | 1 << bit;
+                    if let Some(p) = trial.checked_pow(k) {
+                        if p <= n {
+                            root = trial;
+                        }
+                    }
+
+                    if bit == 0 {
+                        break;
+                    }
+                    bit -= 1;
+                }
+
+                root
+            }
+
+            /// Returns the floored logarithm of `n` in the given base.
+            ///
+            /// ## Panics
+            /// Panics if `n` is zero or `base` is less than 2.
+            pub const fn log(n: $t, base: u64) -> $t {
+                if n == 0 {
+                    panic!("input is zero");
+                }
+                log_nonzero(n, base)
             }
 
-            fn log(self, n: u64) -> $t {
-                if self <= 0 {
-                    panic!("cannot take log of a value less than or equal to 0: {}", self)
+            /// Returns the floored logarithm of an `n` already known to be
+            /// non-zero, skipping the check made by `log`.
+            ///
+            /// ## Panics
+            /// Panics if `base` is less than 2.
+            pub(crate) const fn log_nonzero(n: $t, base: u64) -> $t {
+                if base < 2 {
+                    panic!("base is less than 2");
                 }
-                (self as f64).log(n as f64) as $t
+
+                // The position of the highest set bit, which is exact and
+                // avoids a division per bit on the common base 2 path.
+                if base == 2 {
+                    return (<$t>::BITS - 1 - n.leading_zeros()) as $t;
+                }
+
+                // A base which does not fit in the type is larger than every
+                // value of it.
+                if <$t>::BITS < 64 && base > <$t>::MAX as u64 {
+                    return 0;
+                }
+                let base = base as $t;
+
+                // For other bases, repeated division never overflows and,
+                // unlike `f64::log`, is not subject to rounding at exact
+                // powers.
+                let mut n = n;
+                let mut log = 0;
+                while n >= base {
+                    n /= base;
+                    log += 1;
+                }
+
+                log
+            }
+
+            /// Returns the floored base 2 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` is zero.
+            pub const fn log2(n: $t) -> $t {
+                log(n, 2)
+            }
+
+            /// Returns the floored base 10 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` is zero.
+            pub const fn log10(n: $t) -> $t {
+                log(n, 10)
+            }
+
+            /// Returns the ceiling of the logarithm of `n` in the given base.
+            ///
+            /// ## Panics
+            /// Panics if `n` is zero or `base` is less than 2.
+            pub const fn log_ceil(n: $t, base: u64) -> $t {
+                let floor = log(n, base);
+
+                // The floored power is at most `n` so cannot overflow. A base
+                // wider than the type only has the exact power 1.
+                let exact = if <$t>::BITS < 64 && base > <$t>::MAX as u64 {
+                    n == 1
+                } else {
+                    (base as $t).pow(floor as u32) == n
+                };
+
+                if exact { floor } else { floor + 1 }
+            }
+
+            /// Returns the ceiling of the base 2 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` is zero.
+            pub const fn log2_ceil(n: $t) -> $t {
+                log_ceil(n, 2)
+            }
+
+            /// Returns the ceiling of the base 10 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` is zero.
+            pub const fn log10_ceil(n: $t) -> $t {
+                log_ceil(n, 10)
             }
         }
     };
 }
 
-macro_rules! impl_uint_trait {
-    ($t:ty) => {
-        impl IntTraits<$t> for $t {
-            fn sqrt(self) -> $t {
-                (self as f64).sqrt() as $t
+macro_rules! impl_int_consts {
+    ($t:ident, $ut:ident) => {
+        #[doc = concat!("Constant functions on `", stringify!($t), "`.")]
+        ///
+        /// These are the algorithms behind `IntTraits`, exposed as `const fn`
+        /// so they can be evaluated at compile time, such as for array
+        /// lengths. A constant function cannot format its panic message, so
+        /// these panic with a fixed message on invalid input.
+        pub mod $t {
+            /// Takes the floored square root `r` of `n` along with the
+            /// remainder `n - r^2`.
+            ///
+            /// ## Panics
+            /// Panics if `n` is negative.
+            pub const fn sqrt_rem(n: $t) -> ($t, $t) {
+                if n < 0 {
+                    panic!("input is negative");
+                }
+                let (root, rem) = ::$ut::sqrt_rem(n as $ut);
+                (root as $t, rem as $t)
             }
 
-            fn cbrt(self) -> $t {
-                (self as f64).cbrt() as $t
+            /// Takes the floored square root of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` is negative.
+            pub const fn sqrt(n: $t) -> $t {
+                sqrt_rem(n).0
             }
 
-            fn log(self, n: u64) -> $t {
-                if self == 0 {
-                    panic!("cannot take log of a value less than or equal to 0: {}", self)
+            /// Takes the ceiling of the square root of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` is negative.
+            pub const fn sqrt_ceil(n: $t) -> $t {
+                if n < 0 {
+                    panic!("input is negative");
+                }
+                ::$ut::sqrt_ceil(n as $ut) as $t
+            }
+
+            /// Takes the floored cubic root `r` of `n` along with the
+            /// remainder `n - r^3`, flooring toward negative infinity.
+            pub const fn cbrt_rem(n: $t) -> ($t, $t) {
+                let (root, rem) = ::$ut::cbrt_rem(n.unsigned_abs());
+                if n >= 0 {
+                    return (root as $t, rem as $t);
+                }
+                if rem == 0 {
+                    return (-(root as $t), 0);
+                }
+
+                // The floored root of a negative value is one past the
+                // negated root of its magnitude `m`. The remainder is then
+                // `(r + 1)^3 - m`, expanded so that no cube is formed.
+                let rem = 3 * root * (root + 1) + 1 - rem;
+                (-(root as $t) - 1, rem as $t)
+            }
+
+            /// Takes the floored cubic root of `n`, flooring toward negative
+            /// infinity.
+            pub const fn cbrt(n: $t) -> $t {
+                cbrt_rem(n).0
+            }
+
+            /// Takes the ceiling of the cubic root of `n`, rounding toward
+            /// positive infinity.
+            pub const fn cbrt_ceil(n: $t) -> $t {
+                if n >= 0 {
+                    return ::$ut::cbrt_ceil(n as $ut) as $t;
+                }
+                -(::$ut::cbrt(n.unsigned_abs()) as $t)
+            }
+
+            /// Takes the floored `k`-th root of `n`, flooring odd roots of
+            /// negative numbers toward negative infinity.
+            ///
+            /// ## Panics
+            /// Panics if `k` is zero, or if `n` is negative and `k` is even.
+            pub const fn nth_root(n: $t, k: u32) -> $t {
+                if k == 0 {
+                    panic!("root degree must be positive");
+                }
+                if n >= 0 {
+                    return ::$ut::nth_root(n as $ut, k) as $t;
+                }
+                if k % 2 == 0 {
+                    panic!("input is negative");
+                }
+
+                // Flooring a negative root is negating the ceiling of the root
+                // of the magnitude. The root only reaches the magnitude of
+                // `MIN` when `k` is 1, where the negation wraps back to `MIN`.
+                let m = n.unsigned_abs();
+                let root = ::$ut::nth_root(m, k);
+                let root = if root.pow(k) == m { root } else { root + 1 };
+                (root as $t).wrapping_neg()
+            }
+
+            /// Returns the floored logarithm of `n` in the given base.
+            ///
+            /// ## Panics
+            /// Panics if `n` <= 0 or `base` is less than 2.
+            pub const fn log(n: $t, base: u64) -> $t {
+                if n < 0 {
+                    panic!("input is negative");
+                }
+                ::$ut::log(n as $ut, base) as $t
+            }
+
+            /// Returns the floored logarithm of an `n` already known to be
+            /// positive, skipping the checks made by `log`.
+            ///
+            /// ## Panics
+            /// Panics if `base` is less than 2.
+            pub(crate) const fn log_nonzero(n: $t, base: u64) -> $t {
+                ::$ut::log_nonzero(n as $ut, base) as $t
+            }
+
+            /// Returns the floored base 2 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` <= 0.
+            pub const fn log2(n: $t) -> $t {
+                log(n, 2)
+            }
+
+            /// Returns the floored base 10 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` <= 0.
+            pub const fn log10(n: $t) -> $t {
+                log(n, 10)
+            }
+
+            /// Returns the ceiling of the logarithm of `n` in the given base.
+            ///
+            /// ## Panics
+            /// Panics if `n` <= 0 or `base` is less than 2.
+            pub const fn log_ceil(n: $t, base: u64) -> $t {
+                if n < 0 {
+                    panic!("input is negative");
                 }
-                (self as f64).log(n as f64) as $t
+                ::$ut::log_ceil(n as $ut, base) as $t
+            }
+
+            /// Returns the ceiling of the base 2 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` <= 0.
+            pub const fn log2_ceil(n: $t) -> $t {
+                log_ceil(n, 2)
+            }
+
+            /// Returns the ceiling of the base 10 logarithm of `n`.
+            ///
+            /// ## Panics
+            /// Panics if `n` <= 0.
+            pub const fn log10_ceil(n: $t) -> $t {
+                log_ceil(n, 10)
             }
         }
     };
 }
 
-impl_int_trait!(i8);
-impl_int_trait!(i16);
-impl_int_trait!(i32);
-impl_int_trait!(i64);
-impl_int_trait!(isize);
+// The primes up to 127, the largest exponent of any power in 128 bits.
+const PRIME_EXPONENTS: [u32; 31] = [
+    2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127,
+];
 
-impl_uint_trait!(u8);
-impl_uint_trait!(u16);
-impl_uint_trait!(u32);
-impl_uint_trait!(u64);
-impl_uint_trait!(usize);
+/// Finds the largest exponent `k > 1` for which `n` is a perfect `k`th power,
+/// optionally considering only odd exponents.
+///
+/// A `k`th power is a `p`th power for every prime `p` dividing `k`, so only
+/// prime exponents are tried, taking the root each time one matches and
+/// multiplying the exponents together. Squares and cubes are first passed
+/// through the residue filters, which reject most inputs without a root.
+fn largest_power<T>(mut n: T, odd: bool) -> Option<(T, u32)>
+where
+    T: PrimitiveInt + IntSqrt<Output = T> + IntCbrt<Output = T> + IntRoot<Output = T>,
+{
+    if n <= T::ONE {
+        return None;
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::IntTraits;
+    let mut k = 1;
+    'primes: for &p in PRIME_EXPONENTS.iter().filter(|&&p| !odd || p != 2) {
+        loop {
+            // No larger exponent is possible once the base would be 1.
+            if p > T::BITS - 1 - n.leading_zeros() {
+                break 'primes;
+            }
 
-    #[test]
-    fn unsigned_sqrt_overall() {
-        assert_eq!(63_u8.sqrt(), 7);
-        assert_eq!(63_u16.sqrt(), 7);
-        assert_eq!(63_u32.sqrt(), 7);
-        assert_eq!(63_u64.sqrt(), 7);
-        assert_eq!(63_usize.sqrt(), 7);
+            let root = match p {
+                2 if IntSqrt::is_square(n) => IntSqrt::sqrt(n),
+                3 if IntCbrt::is_perfect_cube(n) => IntCbrt::cbrt(n),
+                2 | 3 => break,
+                _ => {
+                    let root = IntRoot::nth_root(n, p);
+                    if root.checked_pow(p) != Some(n) {
+                        break;
+                    }
+                    root
+                }
+            };
+            n = root;
+            k *= p;
+        }
     }
 
-    #[test]
-    fn signed_sqrt_overall() {
-        assert_eq!(63_i8.sqrt(), 7);
-        assert_eq!(63_i16.sqrt(), 7);
-        assert_eq!(63_i32.sqrt(), 7);
-        assert_eq!(63_i64.sqrt(), 7);
-        assert_eq!(63_isize.sqrt(), 7);
+    if k > 1 {
+        Some((n, k))
+    } else {
+        None
     }
+}
 
-    #[test]
-    fn unsigned_cbrt_overall() {
-        assert_eq!(247_u8.cbrt(), 6);
-        assert_eq!(891_u16.cbrt(), 9);
-        assert_eq!(891_u32.cbrt(), 9);
-        assert_eq!(891_u64.cbrt(), 9);
-        assert_eq!(891_usize.cbrt(), 9);
+/// Takes the greatest common divisor of two unsigned values with Stein's
+/// algorithm, which needs only shifts and subtractions.
+fn binary_gcd<T: PrimitiveInt>(mut a: T, mut b: T) -> T {
+    if a == T::ZERO {
+        return b;
+    }
+    if b == T::ZERO {
+        return a;
     }
 
-    #[test]
-    fn signed_cbrt_overall() {
-        assert_eq!(113_i8.cbrt(), 4);
-        assert_eq!(891_i16.cbrt(), 9);
-        assert_eq!(891_i32.cbrt(), 9);
-        assert_eq!(891_i64.cbrt(), 9);
-        assert_eq!(891_isize.cbrt(), 9);
+    // The common factors of 2 are restored at the end, after which both
+    // values are kept odd so that their difference is even.
+    let shift = (a | b).trailing_zeros();
+    a = a >> a.trailing_zeros();
+    loop {
+        b = b >> b.trailing_zeros();
+        if a > b {
+            core::mem::swap(&mut a, &mut b);
+        }
+        b = b - a;
+        if b == T::ZERO {
+            return a << shift;
+        }
+    }
+}
+
+/// Runs the extended Euclidean algorithm on two unsigned values, returning
+/// their greatest common divisor `g` and the magnitudes of Bézout coefficients
+/// `x` and `y`, along with whether `x` is the negative one.
+///
+/// The coefficients alternate in sign, so only their magnitudes are tracked,
+/// and the final step, whose coefficients are the inputs divided by `g`, is
+/// never taken. Every magnitude is then at most half of the larger input
+/// divided by `g`, so nothing can overflow and the results fit in the signed
+/// type of the same width without widening.
+fn extended_euclid<T: PrimitiveInt>(a: T, b: T) -> (T, T, T, bool) {
+    if b == T::ZERO {
+        return (a, T::ONE, T::ZERO, false);
+    }
+
+    let (mut r0, mut r1) = (a, b);
+    let (mut s0, mut s1) = (T::ONE, T::ZERO);
+    let (mut t0, mut t1) = (T::ZERO, T::ONE);
+    let mut x_negative = true;
+    loop {
+        let q = r0 / r1;
+        let r2 = r0 % r1;
+        if r2 == T::ZERO {
+            return (r1, s1, t1, x_negative);
+        }
+
+        let s2 = s0 + q * s1;
+        let t2 = t0 + q * t1;
+        r0 = r1;
+        r1 = r2;
+        s0 = s1;
+        s1 = s2;
+        t0 = t1;
+        t1 = t2;
+        x_negative = !x_negative;
+    }
+}
+
+/// Adds two residues modulo `m` without overflowing, even when `m` is close
+/// to the largest value of the type.
+fn add_mod_reduced<T: PrimitiveInt>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Subtracts two residues modulo `m` without overflowing.
+fn sub_mod_reduced<T: PrimitiveInt>(a: T, b: T, m: T) -> T {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+/// Multiplies two residues modulo `m` by doubling and adding one bit of `b`
+/// at a time, for types which have no wider type to multiply in.
+fn mul_mod_ladder<T: PrimitiveInt>(a: T, b: T, m: T) -> T {
+    let mut result = T::ZERO;
+    for i in (0..T::BITS - b.leading_zeros()).rev() {
+        result = add_mod_reduced(result, result, m);
+        if (b >> i) & T::ONE == T::ONE {
+            result = add_mod_reduced(result, a, m);
+        }
+    }
+    result
+}
+
+/// Raises a residue to `exp` modulo `m` by repeated squaring, given a
+/// multiplication of residues.
+fn pow_mod_with<T: PrimitiveInt, F: Fn(T, T, T) -> T>(mut base: T, mut exp: T, m: T, mul: F) -> T {
+    let mut result = T::ONE % m;
+    while exp != T::ZERO {
+        if exp & T::ONE == T::ONE {
+            result = mul(result, base, m);
+        }
+        exp = exp >> 1;
+        if exp != T::ZERO {
+            base = mul(base, base, m);
+        }
+    }
+    result
+}
+
+macro_rules! impl_uint_modular {
+    ($t:ident, $wide:ident) => {
+        impl_uint_modular!(@impl $t, |a: $t, b: $t, m: $t| (a as $wide * b as $wide % m as $wide) as $t);
+    };
+    ($t:ident) => {
+        impl_uint_modular!(@impl $t, mul_mod_ladder);
+    };
+    (@impl $t:ident, $mul:expr) => {
+        impl IntModular for $t {
+            type Output = $t;
+
+            fn try_pow_mod(self, exp: $t, m: $t) -> Result<$t, IntTraitsError<$t>> {
+                if m == 0 {
+                    return Err(IntTraitsError::ZeroInput);
+                }
+                Ok(pow_mod_with(self % m, exp, m, $mul))
+            }
+
+            fn try_add_mod(self, other: $t, m: $t) -> Result<$t, IntTraitsError<$t>> {
+                if m == 0 {
+                    return Err(IntTraitsError::ZeroInput);
+                }
+                Ok(add_mod_reduced(self % m, other % m, m))
+            }
+
+            fn try_sub_mod(self, other: $t, m: $t) -> Result<$t, IntTraitsError<$t>> {
+                if m == 0 {
+                    return Err(IntTraitsError::ZeroInput);
+                }
+                Ok(sub_mod_reduced(self % m, other % m, m))
+            }
+
+            fn try_mul_mod(self, other: $t, m: $t) -> Result<$t, IntTraitsError<$t>> {
+                if m == 0 {
+                    return Err(IntTraitsError::ZeroInput);
+                }
+                Ok($mul(self % m, other % m, m))
+            }
+        }
+    };
+}
+
+macro_rules! impl_int_modular {
+    ($t:ident) => {
+        impl IntModular for $t {
+            type Output = $t;
+
+            fn try_pow_mod(self, exp: $t, m: $t) -> Result<$t, IntTraitsError<$t>> {
+                if exp < 0 {
+                    return Err(IntTraitsError::NegativeInput(exp));
+                }
+                if m == 0 {
+                    return Err(IntTraitsError::ZeroInput);
+                }
+
+                // The result is below `|m|`, which is at most `MAX + 1`, so
+                // it fits.
+                let m = m.unsigned_abs();
+                IntModular::try_pow_mod(impl_int_modular!(@residue self, m), exp.unsigned_abs(), m)
+                    .map(|r| r as $t)
+                    .map_err(|e| e.map(|n| n as $t))
+            }
+
+            impl_int_modular!(@op $t, try_add_mod);
+            impl_int_modular!(@op $t, try_sub_mod);
+            impl_int_modular!(@op $t, try_mul_mod);
+        }
+    };
+    (@op $t:ident, $f:ident) => {
+        fn $f(self, other: $t, m: $t) -> Result<$t, IntTraitsError<$t>> {
+            if m == 0 {
+                return Err(IntTraitsError::ZeroInput);
+            }
+
+            // Euclidean residues are non-negative, so the operation is done
+            // on them as unsigned values.
+            let m = m.unsigned_abs();
+            let (a, b) = (impl_int_modular!(@residue self, m), impl_int_modular!(@residue other, m));
+            IntModular::$f(a, b, m)
+                .map(|r| r as $t)
+                .map_err(|e| e.map(|n| n as $t))
+        }
+    };
+    // The Euclidean residue of `$n` modulo the unsigned `$m`, which unlike
+    // `rem_euclid` cannot overflow for `MIN` and -1.
+    (@residue $n:expr, $m:expr) => {{
+        let r = $n.unsigned_abs() % $m;
+        if $n < 0 && r != 0 {
+            $m - r
+        } else {
+            r
+        }
+    }};
+}
+
+// The number of odd divisors, from 3 to 47, tried by trial division before
+// any probable prime test, which rejects most composites cheaply.
+const TRIAL_DIVISORS: u32 = 23;
+
+// Miller-Rabin bases which together admit no strong pseudoprime below 2^32,
+// found by Jaeschke, and below 2^64, found by Sinclair.
+const MILLER_RABIN_BASES_32: [u32; 3] = [2, 7, 61];
+const MILLER_RABIN_BASES_64: [u64; 7] = [2, 325, 9375, 28178, 450775, 9780504, 1795265022];
+
+/// Decides primality by trial division by the small odd numbers, or returns
+/// `None` if `n` has no small factor but is too large to be known prime.
+fn trial_division<T: PrimitiveInt>(n: T) -> Option<bool> {
+    let two = T::ONE + T::ONE;
+    if n < two {
+        return Some(false);
+    }
+    if n.trailing_zeros() > 0 {
+        return Some(n == two);
+    }
+
+    // Composite divisors are redundant but cheap, and once a divisor exceeds
+    // the square root of `n` without dividing it, `n` is prime.
+    let mut d = two + T::ONE;
+    for _ in 0..TRIAL_DIVISORS {
+        if n / d < d {
+            return Some(true);
+        }
+        if n % d == T::ZERO {
+            return Some(false);
+        }
+        d = d + two;
+    }
+    None
+}
+
+/// Returns whether the odd `n` is a strong probable prime to `base`.
+fn miller_rabin<T: PrimitiveInt + IntModular<Output = T>>(n: T, base: T) -> bool {
+    let n_minus_1 = n - T::ONE;
+    let s = n_minus_1.trailing_zeros();
+    let mut x = IntModular::pow_mod(base, n_minus_1 >> s, n);
+    if x == T::ONE || x == n_minus_1 {
+        return true;
+    }
+    for _ in 1..s {
+        x = IntModular::mul_mod(x, x, n);
+        if x == n_minus_1 {
+            return true;
+        }
+    }
+    false
+}
+
+/// Returns the Jacobi symbol `(a / n)` for odd `n`.
+fn jacobi(mut a: u128, mut n: u128) -> i32 {
+    let mut result = 1;
+    a %= n;
+    while a != 0 {
+        // Each factor of 2 flips the sign when `n` is 3 or 5 modulo 8.
+        let twos = a.trailing_zeros();
+        a >>= twos;
+        if twos % 2 == 1 && (n % 8 == 3 || n % 8 == 5) {
+            result = -result;
+        }
+        core::mem::swap(&mut a, &mut n);
+        if a % 4 == 3 && n % 4 == 3 {
+            result = -result;
+        }
+        a %= n;
+    }
+    if n == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Returns whether the odd `n` with no small factor is a strong Lucas
+/// probable prime, with the parameters chosen by Selfridge's method.
+fn strong_lucas(n: u128) -> bool {
+    // No suitable `D` exists for a square, so the search would never end.
+    if IntSqrt::is_square(n) {
+        return false;
+    }
+
+    // Find the first of 5, -7, 9, -11, ... whose Jacobi symbol is -1. The
+    // magnitude stays far below `n`, so its residue is found directly.
+    let mut d: i64 = 5;
+    loop {
+        let residue = if d > 0 { d as u128 } else { n - d.unsigned_abs() as u128 };
+        match jacobi(residue, n) {
+            -1 => break,
+            0 => return false,
+            _ => d = if d > 0 { -d - 2 } else { -d + 2 },
+        }
+    }
+
+    let residue = |k: i64| if k >= 0 { k as u128 % n } else { n - k.unsigned_abs() as u128 % n };
+    let d_mod = residue(d);
+    let q = residue((1 - d) / 4);
+    let half = |x: u128| if x & 1 == 0 { x / 2 } else { x / 2 + n / 2 + 1 };
+    let mul = |a: u128, b: u128| mul_mod_ladder(a, b, n);
+
+    // Walk the bits of `n + 1 = k * 2^s` with `P = 1`, keeping `U_k`, `V_k`
+    // and `Q^k` modulo `n`.
+    let n_plus_1 = n + 1;
+    let s = n_plus_1.trailing_zeros();
+    let k = n_plus_1 >> s;
+    let (mut u, mut v, mut qk) = (1, 1, q);
+    for i in (0..127 - k.leading_zeros()).rev() {
+        u = mul(u, v);
+        v = sub_mod_reduced(mul(v, v), add_mod_reduced(qk, qk, n), n);
+        qk = mul(qk, qk);
+        if (k >> i) & 1 == 1 {
+            let next_u = half(add_mod_reduced(u, v, n));
+            v = half(add_mod_reduced(mul(d_mod, u), v, n));
+            u = next_u;
+            qk = mul(qk, q);
+        }
+    }
+
+    if u == 0 || v == 0 {
+        return true;
+    }
+    for _ in 1..s {
+        v = sub_mod_reduced(mul(v, v), add_mod_reduced(qk, qk, n), n);
+        qk = mul(qk, qk);
+        if v == 0 {
+            return true;
+        }
+    }
+    false
+}
+
+macro_rules! impl_int_trait {
+    ($t:ident) => {
+        impl IntSqrt for $t {
+            type Output = $t;
+
+            fn try_sqrt_rem(self) -> Result<($t, $t), IntTraitsError<$t>> {
+                if self < 0 {
+                    return Err(IntTraitsError::NegativeInput(self));
+                }
+                Ok(::$t::sqrt_rem(self))
+            }
+
+            fn try_sqrt_ceil(self) -> Result<$t, IntTraitsError<$t>> {
+                if self < 0 {
+                    return Err(IntTraitsError::NegativeInput(self));
+                }
+                Ok(::$t::sqrt_ceil(self))
+            }
+
+            fn is_square(self) -> bool {
+                self >= 0 && IntSqrt::is_square(self.unsigned_abs())
+            }
+        }
+
+        impl IntCbrt for $t {
+            type Output = $t;
+
+            fn try_cbrt_rem(self) -> Result<($t, $t), IntTraitsError<$t>> {
+                Ok(::$t::cbrt_rem(self))
+            }
+
+            fn try_cbrt_ceil(self) -> Result<$t, IntTraitsError<$t>> {
+                Ok(::$t::cbrt_ceil(self))
+            }
+
+            fn is_perfect_cube(self) -> bool {
+                // The cube of a negative number is the negated cube of its
+                // magnitude.
+                IntCbrt::is_perfect_cube(self.unsigned_abs())
+            }
+        }
+
+        impl IntRoot for $t {
+            type Output = $t;
+
+            fn try_nth_root(self, k: u32) -> Result<$t, IntTraitsError<$t>> {
+                if k == 0 {
+                    return Err(IntTraitsError::InvalidDegree(k));
+                }
+                if self < 0 && k % 2 == 0 {
+                    return Err(IntTraitsError::NegativeInput(self));
+                }
+                Ok(::$t::nth_root(self, k))
+            }
+
+            fn perfect_power(self) -> Option<($t, u32)> {
+                if self >= 0 {
+                    return IntRoot::perfect_power(self.unsigned_abs()).map(|(root, k)| (root as $t, k));
+                }
+
+                // Only odd exponents preserve the sign.
+                largest_power(self.unsigned_abs(), true).map(|(root, k)| (-(root as $t), k))
+            }
+        }
+
+        impl IntLog for $t {
+            type Output = $t;
+
+            fn try_log(self, n: u64) -> Result<$t, IntTraitsError<$t>> {
+                if self < 0 {
+                    return Err(IntTraitsError::NegativeInput(self));
+                }
+                if self == 0 {
+                    return Err(IntTraitsError::ZeroInput);
+                }
+                if n < 2 {
+                    return Err(IntTraitsError::InvalidBase(n));
+                }
+                Ok(::$t::log_nonzero(self, n))
+            }
+
+            fn try_log_ceil(self, n: u64) -> Result<$t, IntTraitsError<$t>> {
+                IntLog::try_log(self, n)?;
+                Ok(::$t::log_ceil(self, n))
+            }
+        }
+
+        impl IntGcd for $t {
+            type Output = $t;
+            type Coefficient = $t;
+
+            fn try_gcd(self, other: $t) -> Result<$t, IntTraitsError<$t>> {
+                // Only `gcd(MIN, MIN)` and `gcd(MIN, 0)` exceed `MAX`.
+                let gcd = binary_gcd(self.unsigned_abs(), other.unsigned_abs());
+                <$t>::try_from(gcd).map_err(|_| IntTraitsError::Overflow(self))
+            }
+
+            fn try_lcm(self, other: $t) -> Result<$t, IntTraitsError<$t>> {
+                let (a, b) = (self.unsigned_abs(), other.unsigned_abs());
+                if a == 0 || b == 0 {
+                    return Ok(0);
+                }
+                (a / binary_gcd(a, b))
+                    .checked_mul(b)
+                    .and_then(|lcm| <$t>::try_from(lcm).ok())
+                    .ok_or(IntTraitsError::Overflow(self))
+            }
+
+            fn try_extended_gcd(self, other: $t) -> Result<($t, $t, $t), IntTraitsError<$t>> {
+                let (g, x, y, x_negative) = extended_euclid(self.unsigned_abs(), other.unsigned_abs());
+                let g = <$t>::try_from(g).map_err(|_| IntTraitsError::Overflow(self))?;
+                let (mut x, mut y) = (x as $t, y as $t);
+                if x_negative {
+                    x = -x;
+                } else {
+                    y = -y;
+                }
+                if self < 0 {
+                    x = -x;
+                }
+                if other < 0 {
+                    y = -y;
+                }
+                Ok((g, x, y))
+            }
+
+            fn mod_inverse(self, m: $t) -> Option<$t> {
+                if m <= 0 {
+                    return None;
+                }
+                IntGcd::mod_inverse(self.rem_euclid(m).unsigned_abs(), m.unsigned_abs()).map(|inv| inv as $t)
+            }
+        }
+    };
+}
+
+macro_rules! impl_uint_trait {
+    ($t:ident, $it:ident) => {
+        impl IntSqrt for $t {
+            type Output = $t;
+
+            fn try_sqrt_rem(self) -> Result<($t, $t), IntTraitsError<$t>> {
+                Ok(::$t::sqrt_rem(self))
+            }
+
+            fn try_sqrt_ceil(self) -> Result<$t, IntTraitsError<$t>> {
+                Ok(::$t::sqrt_ceil(self))
+            }
+
+            fn is_square(self) -> bool {
+                if SQUARES_MOD_64 >> (self as u32 & 63) & 1 == 0 {
+                    return false;
+                }
+
+                // Types narrower than the modulus are already reduced.
+                let r = match <$t>::try_from(SQUARES_MODULUS) {
+                    Ok(m) => (self % m) as u32,
+                    Err(_) => self as u32,
+                };
+                if SQUARES_MOD_63 >> (r % 63) & 1 == 0
+                    || SQUARES_MOD_65 >> (r % 65) & 1 == 0
+                    || SQUARES_MOD_11 >> (r % 11) & 1 == 0
+                {
+                    return false;
+                }
+
+                ::$t::sqrt_rem(self).1 == 0
+            }
+        }
+
+        impl IntCbrt for $t {
+            type Output = $t;
+
+            fn try_cbrt_rem(self) -> Result<($t, $t), IntTraitsError<$t>> {
+                Ok(::$t::cbrt_rem(self))
+            }
+
+            fn try_cbrt_ceil(self) -> Result<$t, IntTraitsError<$t>> {
+                Ok(::$t::cbrt_ceil(self))
+            }
+
+            fn is_perfect_cube(self) -> bool {
+                if CUBES_MOD_63 >> (self % 63) as u32 & 1 == 0
+                    || CUBES_MOD_13 >> (self % 13) as u32 & 1 == 0
+                {
+                    return false;
+                }
+                ::$t::cbrt_rem(self).1 == 0
+            }
+        }
+
+        impl IntRoot for $t {
+            type Output = $t;
+
+            fn try_nth_root(self, k: u32) -> Result<$t, IntTraitsError<$t>> {
+                if k == 0 {
+                    return Err(IntTraitsError::InvalidDegree(k));
+                }
+                Ok(::$t::nth_root(self, k))
+            }
+
+            fn perfect_power(self) -> Option<($t, u32)> {
+                largest_power(self, false)
+            }
+        }
+
+        impl IntLog for $t {
+            type Output = $t;
+
+            fn try_log(self, n: u64) -> Result<$t, IntTraitsError<$t>> {
+                if self == 0 {
+                    return Err(IntTraitsError::ZeroInput);
+                }
+                if n < 2 {
+                    return Err(IntTraitsError::InvalidBase(n));
+                }
+                Ok(::$t::log_nonzero(self, n))
+            }
+
+            fn try_log_ceil(self, n: u64) -> Result<$t, IntTraitsError<$t>> {
+                IntLog::try_log(self, n)?;
+                Ok(::$t::log_ceil(self, n))
+            }
+        }
+
+        impl IntGcd for $t {
+            type Output = $t;
+            type Coefficient = $it;
+
+            fn try_gcd(self, other: $t) -> Result<$t, IntTraitsError<$t>> {
+                Ok(binary_gcd(self, other))
+            }
+
+            fn try_lcm(self, other: $t) -> Result<$t, IntTraitsError<$t>> {
+                if self == 0 || other == 0 {
+                    return Ok(0);
+                }
+                (self / binary_gcd(self, other))
+                    .checked_mul(other)
+                    .ok_or(IntTraitsError::Overflow(self))
+            }
+
+            fn try_extended_gcd(self, other: $t) -> Result<($t, $it, $it), IntTraitsError<$t>> {
+                let (g, x, y, x_negative) = extended_euclid(self, other);
+                let (x, y) = (x as $it, y as $it);
+                Ok(if x_negative { (g, -x, y) } else { (g, x, -y) })
+            }
+
+            fn mod_inverse(self, m: $t) -> Option<$t> {
+                if m == 0 {
+                    return None;
+                }
+
+                // The coefficient of `m` is not needed, and that of `self`
+                // is at most `m / 2`, so it is brought into range with a
+                // single subtraction.
+                let (g, x, _, x_negative) = extended_euclid(self % m, m);
+                if g != 1 {
+                    return None;
+                }
+                Some(if x_negative && x != 0 { m - x } else { x })
+            }
+        }
+    };
+}
+
+impl_uint_consts!(u8);
+impl_uint_consts!(u16);
+impl_uint_consts!(u32);
+impl_uint_consts!(u64);
+impl_uint_consts!(u128);
+impl_uint_consts!(usize);
+
+impl_int_consts!(i8, u8);
+impl_int_consts!(i16, u16);
+impl_int_consts!(i32, u32);
+impl_int_consts!(i64, u64);
+impl_int_consts!(i128, u128);
+impl_int_consts!(isize, usize);
+
+impl_int_trait!(i8);
+impl_int_trait!(i16);
+impl_int_trait!(i32);
+impl_int_trait!(i64);
+impl_int_trait!(i128);
+impl_int_trait!(isize);
+
+impl_uint_trait!(u8, i8);
+impl_uint_trait!(u16, i16);
+impl_uint_trait!(u32, i32);
+impl_uint_trait!(u64, i64);
+impl_uint_trait!(u128, i128);
+impl_uint_trait!(usize, isize);
+
+impl_uint_modular!(u8, u16);
+impl_uint_modular!(u16, u32);
+impl_uint_modular!(u32, u64);
+impl_uint_modular!(u64, u128);
+impl_uint_modular!(u128);
+impl_uint_modular!(usize, u128);
+
+impl_int_modular!(i8);
+impl_int_modular!(i16);
+impl_int_modular!(i32);
+impl_int_modular!(i64);
+impl_int_modular!(i128);
+impl_int_modular!(isize);
+
+impl IntPrime for u8 {
+    fn is_prime(self) -> bool {
+        IntPrime::is_prime(self as u32)
+    }
+}
+
+impl IntPrime for u16 {
+    fn is_prime(self) -> bool {
+        IntPrime::is_prime(self as u32)
+    }
+}
+
+impl IntPrime for u32 {
+    fn is_prime(self) -> bool {
+        if let Some(prime) = trial_division(self) {
+            return prime;
+        }
+        MILLER_RABIN_BASES_32.iter().all(|&base| miller_rabin(self, base))
+    }
+}
+
+impl IntPrime for u64 {
+    fn is_prime(self) -> bool {
+        if let Ok(n) = u32::try_from(self) {
+            return IntPrime::is_prime(n);
+        }
+        if let Some(prime) = trial_division(self) {
+            return prime;
+        }
+        MILLER_RABIN_BASES_64
+            .iter()
+            .all(|&base| miller_rabin(self, base))
+    }
+}
+
+impl IntPrime for u128 {
+    fn is_prime(self) -> bool {
+        if let Ok(n) = u64::try_from(self) {
+            return IntPrime::is_prime(n);
+        }
+        if let Some(prime) = trial_division(self) {
+            return prime;
+        }
+        miller_rabin(self, 2) && strong_lucas(self)
+    }
+}
+
+impl IntPrime for usize {
+    fn is_prime(self) -> bool {
+        IntPrime::is_prime(self as u128)
+    }
+}
+
+macro_rules! impl_int_prime {
+    ($t:ident) => {
+        impl IntPrime for $t {
+            fn is_prime(self) -> bool {
+                self > 0 && IntPrime::is_prime(self.unsigned_abs())
+            }
+        }
+    };
+}
+
+impl_int_prime!(i8);
+impl_int_prime!(i16);
+impl_int_prime!(i32);
+impl_int_prime!(i64);
+impl_int_prime!(i128);
+impl_int_prime!(isize);
+
+#[cfg(test)]
+mod tests {
+    use super::{IntCbrt, IntGcd, IntLog, IntModular, IntPrime, IntRoot, IntSqrt};
+    use super::{IntTraits, IntTraitsError};
+    use std::collections::BTreeMap;
+    use std::string::ToString;
+
+    // The seed of `xorshift`, fixed so failures are reproducible
+    const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+    // Advances the xorshift64 generator and returns its new state.
+    fn xorshift(state: &mut u64) -> u64 {
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+        *state
+    }
+
+    // Evaluated during compilation, so a wrong result fails the build.
+    const _: () = assert!(::u64::sqrt(u64::MAX) == u32::MAX as u64);
+    const _: () = assert!(::u128::sqrt_ceil(u128::MAX) == 1 << 64);
+    const _: () = assert!(::u16::sqrt_rem(63).1 == 14);
+    const _: () = assert!(::i32::cbrt(-28) == -4);
+    const _: () = assert!(::i32::cbrt_ceil(-28) == -3);
+    const _: () = assert!(::u64::cbrt(u64::MAX) == 2642245);
+    const _: () = assert!(::i8::nth_root(i8::MIN, 7) == -2);
+    const _: () = assert!(::u64::nth_root(u64::MAX, 4) == 65535);
+    const _: () = assert!(::u32::log(1000, 10) == 3);
+    const _: () = assert!(::u8::log(200, 300) == 0);
+    const _: () = assert!(::u64::log2(u64::MAX) == 63);
+    const _: () = assert!(::u128::log2(1) == 0);
+    const _: () = assert!(::u8::log2(255) == 7);
+    const _: () = assert!(::i128::log2(i128::MAX) == 126);
+    const _: () = assert!(::i64::log10(i64::MAX) == 18);
+    const _: () = assert!(::usize::log2_ceil(1025) == 11);
+    const _: () = assert!(::u128::log10_ceil(u128::MAX) == 39);
+
+    #[test]
+    fn const_array_length() {
+        const TABLE_BITS: usize = ::usize::log2_ceil(1000);
+        let table = [0_u8; 1 << TABLE_BITS];
+        assert_eq!(table.len(), 1024);
+    }
+
+    #[test]
+    #[should_panic(expected = "base is less than 2")]
+    fn const_invalid_base() {
+        let _ = ::u32::log(5, 1);
+    }
+
+    #[test]
+    fn unsigned_sqrt_overall() {
+        assert_eq!(63_u8.sqrt(), 7);
+        assert_eq!(63_u16.sqrt(), 7);
+        assert_eq!(63_u32.sqrt(), 7);
+        assert_eq!(63_u64.sqrt(), 7);
+        assert_eq!(63_usize.sqrt(), 7);
+    }
+
+    #[test]
+    fn signed_sqrt_overall() {
+        assert_eq!(63_i8.sqrt(), 7);
+        assert_eq!(63_i16.sqrt(), 7);
+        assert_eq!(63_i32.sqrt(), 7);
+        assert_eq!(63_i64.sqrt(), 7);
+        assert_eq!(63_isize.sqrt(), 7);
+    }
+
+    #[test]
+    fn sqrt_exhaustive_u16() {
+        for n in 0..=u16::MAX {
+            let r = n.sqrt() as u32;
+            let n = n as u32;
+            assert!(r * r <= n && n < (r + 1) * (r + 1), "sqrt({}) = {}", n, r);
+        }
+    }
+
+    #[test]
+    fn sqrt_exhaustive_i16() {
+        for n in 0..=i16::MAX {
+            assert_eq!(n.sqrt() as u16, (n as u16).sqrt());
+        }
+    }
+
+    #[test]
+    fn sqrt_large_u64() {
+        assert_eq!(u64::MAX.sqrt(), u32::MAX as u64);
+        assert_eq!(i64::MAX.sqrt(), 3037000499);
+        assert_eq!((u32::MAX as u64 * u32::MAX as u64).sqrt(), u32::MAX as u64);
+        assert_eq!((u32::MAX as u64 * u32::MAX as u64 - 1).sqrt(), u32::MAX as u64 - 1);
+        assert_eq!(((1_u64 << 53) + 1).sqrt(), 94906265);
+    }
+
+    #[test]
+    fn sqrt_random_u64() {
+        let mut state = SEED;
+        for _ in 0..100_000 {
+            let x = xorshift(&mut state);
+
+            let r = x.sqrt() as u128;
+            let n = x as u128;
+            assert!(r * r <= n && n < (r + 1) * (r + 1), "sqrt({}) = {}", n, r);
+        }
+    }
+
+    #[test]
+    fn sqrt_128() {
+        let two_64 = 1_u128 << 64;
+
+        assert_eq!(u128::MAX.sqrt(), u64::MAX as u128);
+        assert_eq!(i128::MAX.sqrt(), 13043817825332782212);
+        assert_eq!(two_64.sqrt(), 1 << 32);
+        assert_eq!((two_64 - 1).sqrt(), (1 << 32) - 1);
+        assert_eq!((two_64 + 1).sqrt(), 1 << 32);
+        assert_eq!((u64::MAX as u128 * u64::MAX as u128).sqrt(), u64::MAX as u128);
+        assert_eq!((u64::MAX as u128 * u64::MAX as u128 - 1).sqrt(), u64::MAX as u128 - 1);
+    }
+
+    #[test]
+    fn sqrt_random_u128() {
+        let mut state = SEED;
+        for _ in 0..100_000 {
+            let hi = xorshift(&mut state);
+            let x = xorshift(&mut state);
+
+            let n = (hi as u128) << 64 | x as u128;
+            let r = n.sqrt();
+            assert!(r * r <= n, "sqrt({}) = {}", n, r);
+            assert!((r + 1).checked_mul(r + 1).is_none_or(|s| n < s), "sqrt({}) = {}", n, r);
+        }
+    }
+
+    macro_rules! check_ceil {
+        ($($t:ty),*) => {$(
+            for n in 0..=<$t>::MAX {
+                let r = n.sqrt();
+                if r * r == n {
+                    assert_eq!(n.sqrt_ceil(), r, "sqrt_ceil({})", n);
+                } else {
+                    assert_eq!(n.sqrt_ceil(), r + 1, "sqrt_ceil({})", n);
+                }
+
+                let r = n.cbrt();
+                if r * r * r == n {
+                    assert_eq!(n.cbrt_ceil(), r, "cbrt_ceil({})", n);
+                } else {
+                    assert_eq!(n.cbrt_ceil(), r + 1, "cbrt_ceil({})", n);
+                }
+
+                if n == 0 {
+                    continue;
+                }
+                for base in 2..=36 {
+                    let l = n.log(base);
+                    if (base as u128).pow(l as u32) == n as u128 {
+                        assert_eq!(n.log_ceil(base), l, "{}.log_ceil({})", n, base);
+                    } else {
+                        assert_eq!(n.log_ceil(base), l + 1, "{}.log_ceil({})", n, base);
+                    }
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    fn ceil_exhaustive() {
+        check_ceil!(i8, i16, u8, u16);
+    }
+
+    #[test]
+    fn ceil_maxima() {
+        assert_eq!(u64::MAX.sqrt_ceil(), 1 << 32);
+        assert_eq!(u128::MAX.sqrt_ceil(), 1 << 64);
+        assert_eq!(i8::MAX.sqrt_ceil(), 12);
+        assert_eq!(i128::MAX.sqrt_ceil(), 13043817825332782213);
+        assert_eq!(u64::MAX.cbrt_ceil(), 2642246);
+        assert_eq!(u128::MAX.cbrt_ceil(), 6981463658332);
+        assert_eq!(u64::MAX.log2_ceil(), 64);
+        assert_eq!(u128::MAX.log2_ceil(), 128);
+        assert_eq!(i128::MAX.log2_ceil(), 127);
+        assert_eq!(u64::MAX.log10_ceil(), 20);
+        assert_eq!(u128::MAX.log10_ceil(), 39);
+        assert_eq!(u64::MAX.log_ceil(u64::MAX), 1);
+        assert_eq!(200_u8.log_ceil(300), 1);
+        assert_eq!(1_u8.log_ceil(300), 0);
+        assert_eq!(1_u64.log2_ceil(), 0);
+        assert_eq!(1024_u64.log2_ceil(), 10);
+        assert_eq!(1025_u64.log2_ceil(), 11);
+        assert_eq!((1_u128 << 127).log2_ceil(), 127);
+    }
+
+    #[test]
+    fn ceil_errors() {
+        assert_eq!((-4_i32).checked_sqrt_ceil(), None);
+        assert_eq!((-8_i32).try_cbrt_ceil(), Ok(-2));
+        assert_eq!(0_u32.checked_log2_ceil(), None);
+        assert_eq!(0_i32.try_log10_ceil(), Err(IntTraitsError::ZeroInput));
+        assert_eq!(50_u32.try_log_ceil(1), Err(IntTraitsError::InvalidBase(1)));
+    }
+
+    macro_rules! check_rem {
+        ($($t:ty),*) => {$(
+            for n in 0..=<$t>::MAX {
+                let (r, rem) = n.sqrt_rem();
+                assert_eq!(r, n.sqrt());
+                assert_eq!(rem as i128, n as i128 - (r as i128).pow(2), "sqrt_rem({})", n);
+
+                let (r, rem) = n.cbrt_rem();
+                assert_eq!(r, n.cbrt());
+                assert_eq!(rem as i128, n as i128 - (r as i128).pow(3), "cbrt_rem({})", n);
+            }
+        )*};
+    }
+
+    #[test]
+    fn rem_exhaustive() {
+        check_rem!(i8, i16, u8, u16);
+    }
+
+    #[test]
+    fn rem_overall() {
+        assert_eq!(63_u32.sqrt_rem(), (7, 14));
+        assert_eq!(64_i32.sqrt_rem(), (8, 0));
+        assert_eq!(u64::MAX.sqrt_rem(), (u32::MAX as u64, 2 * u32::MAX as u64));
+        assert_eq!(u128::MAX.sqrt_rem(), (u64::MAX as u128, 2 * u64::MAX as u128));
+        assert_eq!(891_u64.cbrt_rem(), (9, 162));
+        assert_eq!(u64::MAX.cbrt_rem(), (2642245, 19889396695490));
+        assert_eq!(u128::MAX.cbrt_rem(), (6981463658331, 81751874631114922977532764));
+        assert_eq!((-1_i8).checked_sqrt_rem(), None);
+        assert_eq!((-1_i8).try_cbrt_rem(), Ok((-1, 0)));
+    }
+
+    #[test]
+    fn unsigned_cbrt_overall() {
+        assert_eq!(247_u8.cbrt(), 6);
+        assert_eq!(891_u16.cbrt(), 9);
+        assert_eq!(891_u32.cbrt(), 9);
+        assert_eq!(891_u64.cbrt(), 9);
+        assert_eq!(891_usize.cbrt(), 9);
+    }
+
+    #[test]
+    fn signed_cbrt_overall() {
+        assert_eq!(113_i8.cbrt(), 4);
+        assert_eq!(891_i16.cbrt(), 9);
+        assert_eq!(891_i32.cbrt(), 9);
+        assert_eq!(891_i64.cbrt(), 9);
+        assert_eq!(891_isize.cbrt(), 9);
+    }
+
+    fn is_floor_cbrt(n: i128, r: i128) -> bool {
+        r * r * r <= n && n < (r + 1) * (r + 1) * (r + 1)
+    }
+
+    #[test]
+    fn cbrt_exhaustive_8() {
+        for n in 0..=u8::MAX {
+            assert!(is_floor_cbrt(n as i128, n.cbrt() as i128), "cbrt({})", n);
+        }
+        for n in 0..=i8::MAX {
+            assert!(is_floor_cbrt(n as i128, n.cbrt() as i128), "cbrt({})", n);
+        }
+    }
+
+    #[test]
+    fn cbrt_negative_exhaustive() {
+        for n in i8::MIN..0 {
+            let (r, rem) = n.cbrt_rem();
+            assert!(is_floor_cbrt(n as i128, r as i128), "cbrt({})", n);
+            assert_eq!(rem as i128, n as i128 - (r as i128).pow(3), "cbrt_rem({})", n);
+            assert_eq!(n.cbrt(), r);
+            assert_eq!(n.cbrt_ceil(), if rem == 0 { r } else { r + 1 }, "cbrt_ceil({})", n);
+        }
+        for n in i16::MIN..0 {
+            let (r, rem) = n.cbrt_rem();
+            assert!(is_floor_cbrt(n as i128, r as i128), "cbrt({})", n);
+            assert_eq!(rem as i128, n as i128 - (r as i128).pow(3), "cbrt_rem({})", n);
+            assert_eq!(n.cbrt(), r);
+            assert_eq!(n.cbrt_ceil(), if rem == 0 { r } else { r + 1 }, "cbrt_ceil({})", n);
+        }
+    }
+
+    #[test]
+    fn cbrt_negative() {
+        assert_eq!((-27_i32).cbrt(), -3);
+        assert_eq!((-28_i32).cbrt(), -4);
+        assert_eq!((-28_i32).cbrt_rem(), (-4, 36));
+        assert_eq!((-28_i32).cbrt_ceil(), -3);
+        assert_eq!((-1_i64).cbrt(), -1);
+        assert_eq!(i8::MIN.cbrt_rem(), (-6, 88));
+        assert_eq!(i64::MIN.cbrt(), -(1 << 21));
+        assert_eq!((i64::MIN + 1).cbrt(), -(1 << 21));
+        assert_eq!(i128::MIN.cbrt(), -5541191377757);
+        assert_eq!(i128::MIN.cbrt_ceil(), -5541191377756);
+    }
+
+    #[test]
+    fn cbrt_exhaustive_16() {
+        for n in 0..=u16::MAX {
+            assert!(is_floor_cbrt(n as i128, n.cbrt() as i128), "cbrt({})", n);
+        }
+        for n in 0..=i16::MAX {
+            assert!(is_floor_cbrt(n as i128, n.cbrt() as i128), "cbrt({})", n);
+        }
+    }
+
+    #[test]
+    fn cbrt_large_u64() {
+        let max_cube = 2642245_u64 * 2642245 * 2642245;
+
+        assert_eq!(125_u64.cbrt(), 5);
+        assert_eq!(u64::MAX.cbrt(), 2642245);
+        assert_eq!(max_cube.cbrt(), 2642245);
+        assert_eq!((max_cube - 1).cbrt(), 2642244);
+        assert_eq!(i64::MAX.cbrt(), 2097151);
+        assert_eq!(u32::MAX.cbrt(), 1625);
+    }
+
+    #[test]
+    fn cbrt_128() {
+        let two_64 = 1_u128 << 64;
+
+        assert_eq!(u128::MAX.cbrt(), 6981463658331);
+        assert_eq!(i128::MAX.cbrt(), 5541191377756);
+        assert_eq!(two_64.cbrt(), 2642245);
+        assert_eq!((1_u128 << 63).cbrt(), 1 << 21);
+        assert_eq!(((1_u128 << 63) - 1).cbrt(), (1 << 21) - 1);
+        assert_eq!((6981463658331_u128 * 6981463658331 * 6981463658331 - 1).cbrt(), 6981463658330);
+    }
+
+    #[test]
+    fn cbrt_random_u64() {
+        let mut state = SEED;
+        for _ in 0..100_000 {
+            let x = xorshift(&mut state);
+
+            assert!(is_floor_cbrt(x as i128, x.cbrt() as i128), "cbrt({})", x);
+        }
+    }
+
+    macro_rules! check_nth_root {
+        ($($t:ty),*) => {$(
+            for n in <$t>::MIN..=<$t>::MAX {
+                for k in 1..=17 {
+                    if n < 0 && k % 2 == 0 {
+                        assert_eq!(n.checked_nth_root(k), None);
+                        continue;
+                    }
+
+                    let r = n.nth_root(k) as i128;
+                    let n = n as i128;
+                    assert!(r.pow(k) <= n, "{}.nth_root({}) = {}", n, k, r);
+                    assert!(n < (r + 1).pow(k), "{}.nth_root({}) = {}", n, k, r);
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    #[allow(unused_comparisons)]
+    fn nth_root_exhaustive() {
+        check_nth_root!(i8, i16, u8, u16);
+    }
+
+    #[test]
+    fn nth_root_overall() {
+        assert_eq!((-27_i32).nth_root(3), -3);
+        assert_eq!((-28_i32).nth_root(3), -4);
+        assert_eq!((-26_i32).nth_root(3), -3);
+        assert_eq!(i8::MIN.nth_root(7), -2);
+        assert_eq!(i8::MIN.nth_root(1), i8::MIN);
+        assert_eq!(i128::MIN.nth_root(127), -2);
+        assert_eq!(0_u32.nth_root(5), 0);
+        assert_eq!(1_u32.nth_root(5), 1);
+        assert_eq!(u64::MAX.nth_root(1), u64::MAX);
+        assert_eq!(u64::MAX.nth_root(4), 65535);
+        assert_eq!(u64::MAX.nth_root(5), 7131);
+        assert_eq!(u64::MAX.nth_root(63), 2);
+        assert_eq!(u64::MAX.nth_root(64), 1);
+        assert_eq!(u64::MAX.nth_root(u32::MAX), 1);
+        assert_eq!(u128::MAX.nth_root(4), u32::MAX as u128);
+        assert_eq!(((1_u128 << 100) - 1).nth_root(10), 1023);
+        assert_eq!((1_u128 << 100).nth_root(10), 1024);
+    }
+
+    #[test]
+    fn nth_root_errors() {
+        assert_eq!(8_u32.checked_nth_root(0), None);
+        assert_eq!(8_u32.try_nth_root(0), Err(IntTraitsError::InvalidDegree(0)));
+        assert_eq!((-8_i32).checked_nth_root(3), Some(-2));
+        assert_eq!((-8_i32).try_nth_root(2), Err(IntTraitsError::NegativeInput(-8)));
+    }
+
+    #[test]
+    #[should_panic(expected = "input is negative: -16")]
+    fn even_root_of_negative() {
+        let _ = (-16_i64).nth_root(4);
+    }
+
+    #[test]
+    #[should_panic(expected = "root degree must be positive: 0")]
+    fn zeroth_root() {
+        let _ = 16_u64.nth_root(0);
+    }
+
+    macro_rules! check_perfect_powers {
+        ($($t:ty),*) => {$(
+            // Enumerate every power in range, keeping the smallest base for
+            // each. Positive bases are visited first so are preferred.
+            let mut powers = BTreeMap::new();
+            for b in (2..=256_i128).flat_map(|b| vec![b, -b]) {
+                for k in 2..=16 {
+                    let p = b.pow(k);
+                    if p < <$t>::MIN as i128 || p > <$t>::MAX as i128 {
+                        break;
+                    }
+                    powers.entry(p).or_insert((b, k));
+                }
+            }
+
+            for n in <$t>::MIN..=<$t>::MAX {
+                let square = n >= 0 && n.sqrt_rem().1 == 0;
+                assert_eq!(n.is_square(), square, "is_square({})", n);
+                assert_eq!(n.is_perfect_square(), square, "is_perfect_square({})", n);
+
+                let cube = n.cbrt_rem().1 == 0;
+                assert_eq!(n.is_perfect_cube(), cube, "is_perfect_cube({})", n);
+
+                let expected = powers.get(&(n as i128)).cloned();
+                let actual = n.perfect_power().map(|(b, k)| (b as i128, k));
+                assert_eq!(actual, expected, "perfect_power({})", n);
+            }
+        )*};
+    }
+
+    #[test]
+    #[allow(unused_comparisons)]
+    fn perfect_power_exhaustive() {
+        check_perfect_powers!(i8, i16, u8, u16);
+    }
+
+    #[test]
+    fn is_square_random() {
+        let mut state = SEED;
+        for _ in 0..100_000 {
+            let x = xorshift(&mut state);
+
+            let r = x >> 32;
+            assert!((r * r).is_square(), "is_square({})", r * r);
+            assert_eq!(x.is_square(), x.sqrt_rem().1 == 0, "is_square({})", x);
+            assert!((x as u128 * x as u128).is_square());
+            assert_eq!((x as u128 * x as u128 + 1).is_square(), x == 0);
+        }
+    }
+
+    #[test]
+    fn perfect_power_overall() {
+        assert!(0_u32.is_perfect_square());
+        assert!(1_u32.is_perfect_square());
+        assert!(!(-4_i32).is_perfect_square());
+        assert!((u32::MAX as u64 * u32::MAX as u64).is_perfect_square());
+        assert!(!(u32::MAX as u64 * u32::MAX as u64 - 1).is_perfect_square());
+        assert!(!u64::MAX.is_perfect_square());
+        assert!((u64::MAX as u128 * u64::MAX as u128).is_perfect_square());
+        assert!((-27_i32).is_perfect_cube());
+        assert!(!(-28_i32).is_perfect_cube());
+        assert!((2642245_u64 * 2642245 * 2642245).is_perfect_cube());
+        assert!(i64::MIN.is_perfect_cube());
+
+        assert_eq!(0_u32.perfect_power(), None);
+        assert_eq!(1_u32.perfect_power(), None);
+        assert_eq!((-1_i32).perfect_power(), None);
+        assert_eq!(64_u32.perfect_power(), Some((2, 6)));
+        assert_eq!((-64_i32).perfect_power(), Some((-4, 3)));
+        assert_eq!(i8::MIN.perfect_power(), Some((-2, 7)));
+        assert_eq!(i64::MIN.perfect_power(), Some((-2, 63)));
+        assert_eq!((1_u128 << 127).perfect_power(), Some((2, 127)));
+        assert_eq!((u64::MAX as u128 * u64::MAX as u128).perfect_power(), Some((u64::MAX as u128, 2)));
+        assert_eq!(u64::MAX.perfect_power(), None);
+        assert_eq!(3486784401_u64.perfect_power(), Some((3, 20)));
+        assert_eq!(6_u64.pow(24).perfect_power(), Some((6, 24)));
+        assert_eq!(3_u128.pow(80).perfect_power(), Some((3, 80)));
+        assert_eq!((1_u128 << 126).perfect_power(), Some((2, 126)));
+        assert_eq!((-(1_i64 << 60)).perfect_power(), Some((-16, 15)));
+        assert_eq!((-3_i64).pow(27).perfect_power(), Some((-3, 27)));
+        assert_eq!((-(6_i64.pow(18))).perfect_power(), Some((-36, 9)));
+        assert_eq!((u128::MAX - 1).perfect_power(), None);
+    }
+
+    fn euclid_gcd(mut a: i128, mut b: i128) -> i128 {
+        while b != 0 {
+            let r = a % b;
+            a = b;
+            b = r;
+        }
+        a.abs()
+    }
+
+    macro_rules! check_gcd {
+        ($($t:ty),*) => {$(
+            for a in <$t>::MIN..=<$t>::MAX {
+                for b in <$t>::MIN..=<$t>::MAX {
+                    let gcd = euclid_gcd(a as i128, b as i128);
+                    assert_eq!(a.checked_gcd(b).map(|g| g as i128), Some(gcd).filter(|&g| g <= <$t>::MAX as i128), "gcd({}, {})", a, b);
+
+                    let lcm = if gcd == 0 { 0 } else { (a as i128 * b as i128).abs() / gcd };
+                    assert_eq!(a.checked_lcm(b).map(|l| l as i128), Some(lcm).filter(|&l| l <= <$t>::MAX as i128), "lcm({}, {})", a, b);
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    fn gcd_exhaustive() {
+        check_gcd!(i8, u8);
+    }
+
+    #[test]
+    fn gcd_overall() {
+        use core::num::{Saturating, Wrapping};
+
+        assert_eq!(12_u32.gcd(18), 6);
+        assert_eq!(12_u32.lcm(18), 36);
+        assert_eq!(0_u64.gcd(0), 0);
+        assert_eq!(0_u64.lcm(5), 0);
+        assert_eq!((-12_i32).gcd(18), 6);
+        assert_eq!((-12_i32).lcm(-18), 36);
+        assert_eq!(u128::MAX.gcd(u128::MAX - 1), 1);
+        assert_eq!(u64::MAX.lcm(1), u64::MAX);
+        assert_eq!(u64::MAX.checked_lcm(2), None);
+        assert_eq!((1_u64 << 40).gcd(3 << 45), 1 << 40);
+
+        assert_eq!(i64::MIN.gcd(i64::MAX), 1);
+        assert_eq!(i64::MIN.gcd(6), 2);
+        assert_eq!(i64::MIN.checked_gcd(0), None);
+        assert_eq!(0_i64.checked_gcd(i64::MIN), None);
+        assert_eq!(i64::MIN.checked_gcd(i64::MIN), None);
+        assert_eq!(i64::MIN.try_gcd(0), Err(IntTraitsError::Overflow(i64::MIN)));
+        assert_eq!(i64::MIN.checked_lcm(1), None);
+        assert_eq!((i64::MIN / 2).lcm(2), 1 << 62);
+        assert_eq!(Wrapping(12_i8).gcd(Wrapping(-8)), Wrapping(4));
+        assert_eq!(Saturating(100_u8).checked_lcm(Saturating(3)), None);
+    }
+
+    #[test]
+    fn gcd_random_u64() {
+        let mut state = SEED;
+        for _ in 0..100_000 {
+            let x = xorshift(&mut state);
+            let a = x >> (x % 64);
+            let x = xorshift(&mut state);
+            let b = x >> (x % 64);
+
+            let gcd = euclid_gcd(a as i128, b as i128) as u64;
+            assert_eq!(a.gcd(b), gcd, "gcd({}, {})", a, b);
+            let lcm = (a as u128 * b as u128).checked_div(gcd as u128).unwrap_or(0);
+            assert_eq!(a.checked_lcm(b), super::TryFrom::try_from(lcm).ok(), "lcm({}, {})", a, b);
+        }
+    }
+
+    macro_rules! check_extended_gcd {
+        ($($t:ty),*) => {$(
+            for a in <$t>::MIN..=<$t>::MAX {
+                for b in <$t>::MIN..=<$t>::MAX {
+                    let gcd = euclid_gcd(a as i128, b as i128);
+                    match a.checked_extended_gcd(b) {
+                        Some((g, x, y)) => {
+                            assert_eq!(g as i128, gcd, "extended_gcd({}, {})", a, b);
+                            assert_eq!(a as i128 * x as i128 + b as i128 * y as i128, gcd, "extended_gcd({}, {})", a, b);
+                        }
+                        None => assert!(gcd > <$t>::MAX as i128, "extended_gcd({}, {})", a, b),
+                    }
+
+                    let inverse = a.mod_inverse(b);
+                    if b > 0 && gcd == 1 {
+                        let inv = inverse.unwrap() as i128;
+                        assert!(0 <= inv && inv < b as i128, "mod_inverse({}, {})", a, b);
+                        assert_eq!((a as i128 * inv).rem_euclid(b as i128), 1 % b as i128, "mod_inverse({}, {})", a, b);
+                    } else {
+                        assert_eq!(inverse, None, "mod_inverse({}, {})", a, b);
+                    }
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    fn extended_gcd_exhaustive() {
+        check_extended_gcd!(i8, u8);
+    }
+
+    #[test]
+    fn extended_gcd_overall() {
+        use core::num::Wrapping;
+
+        assert_eq!(240_u32.extended_gcd(46), (2, -9, 47));
+        assert_eq!((-240_i32).extended_gcd(46), (2, 9, 47));
+        assert_eq!(0_u8.extended_gcd(0), (0, 1, 0));
+        assert_eq!(i64::MIN.extended_gcd(i64::MAX), (1, -1, -1));
+        assert_eq!(i64::MIN.checked_extended_gcd(0), None);
+        assert_eq!(i64::MIN.try_extended_gcd(i64::MIN), Err(IntTraitsError::Overflow(i64::MIN)));
+
+        let (g, x, y) = u128::MAX.extended_gcd(u128::MAX - 1);
+        assert_eq!((g, x, y), (1, 1, -1));
+        let (g, x, y) = u128::MAX.extended_gcd(1 << 127);
+        assert_eq!(g, 1);
+        assert_eq!(u128::MAX.wrapping_mul(x as u128).wrapping_add((1_u128 << 127).wrapping_mul(y as u128)), 1);
+
+        assert_eq!(3_u32.mod_inverse(11), Some(4));
+        assert_eq!(10_u32.mod_inverse(17), Some(12));
+        assert_eq!(6_u32.mod_inverse(9), None);
+        assert_eq!(5_u32.mod_inverse(0), None);
+        assert_eq!(5_u32.mod_inverse(1), Some(0));
+        assert_eq!((-3_i32).mod_inverse(11), Some(7));
+        assert_eq!(3_i32.mod_inverse(-11), None);
+        assert_eq!(2_u64.mod_inverse(u64::MAX), Some(u64::MAX / 2 + 1));
+        assert_eq!((u64::MAX - 1).mod_inverse(u64::MAX), Some(u64::MAX - 1));
+        assert_eq!(2_u128.mod_inverse(u128::MAX), Some(u128::MAX / 2 + 1));
+        assert_eq!(i128::MIN.mod_inverse(i128::MAX), Some(i128::MAX - 1));
+        assert_eq!(Wrapping(240_u32).extended_gcd(Wrapping(46)), (Wrapping(2), Wrapping(-9), Wrapping(47)));
+        assert_eq!(Wrapping(3_u8).mod_inverse(Wrapping(11)), Some(Wrapping(4)));
+    }
+
+    #[test]
+    fn extended_gcd_random_u64() {
+        let mut state = SEED;
+        for _ in 0..100_000 {
+            let a = xorshift(&mut state);
+            let x = xorshift(&mut state);
+            // Moduli near `u64::MAX` as well as across the whole range
+            let m = if x & 1 == 0 { u64::MAX - (x >> 48) } else { x };
+
+            let (g, s, t) = a.extended_gcd(m);
+            assert_eq!(g, a.gcd(m));
+            assert_eq!(a as i128 * s as i128 + m as i128 * t as i128, g as i128, "extended_gcd({}, {})", a, m);
+
+            match a.mod_inverse(m) {
+                Some(inv) => {
+                    assert!(inv < m);
+                    assert_eq!(a as u128 * inv as u128 % m as u128, 1, "mod_inverse({}, {})", a, m);
+                }
+                None => assert_ne!(g, 1),
+            }
+        }
+    }
+
+    macro_rules! check_pow_mod {
+        ($($t:ty),*) => {$(
+            for m in (<$t>::MIN..=<$t>::MAX).filter(|&m| m != 0) {
+                for base in <$t>::MIN..=<$t>::MAX {
+                    let mut r = 1_i128.rem_euclid(m as i128);
+                    for exp in 0..=<$t>::MAX {
+                        // Every bit pattern of a small exponent and every
+                        // high bit, while keeping the running time down
+                        if exp < 16 || exp % 16 == 15 {
+                            assert_eq!(base.pow_mod(exp, m) as i128, r, "pow_mod({}, {}, {})", base, exp, m);
+                        }
+                        r = (r * base as i128).rem_euclid(m as i128);
+                    }
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    fn pow_mod_exhaustive() {
+        check_pow_mod!(i8, u8);
+    }
+
+    #[test]
+    fn pow_mod_overall() {
+        use core::num::Wrapping;
+
+        assert_eq!(4_u32.pow_mod(13, 497), 445);
+        assert_eq!(0_u32.pow_mod(0, 7), 1);
+        assert_eq!(5_u32.pow_mod(0, 1), 0);
+        assert_eq!((-2_i32).pow_mod(3, 5), 2);
+        assert_eq!(u64::MAX.pow_mod(u64::MAX, u64::MAX - 1), 1);
+        assert_eq!((u64::MAX - 1).pow_mod(2, u64::MAX), 1);
+        assert_eq!((u128::MAX - 1).pow_mod(3, u128::MAX), u128::MAX - 1);
+        assert_eq!(i128::MIN.pow_mod(2, i128::MAX), 1);
+        assert_eq!(Wrapping(4_u8).pow_mod(Wrapping(13), Wrapping(97)), Wrapping(4_u32.pow_mod(13, 97) as u8));
+
+        // Fermat's little theorem for the Mersenne primes 2^61 - 1 and
+        // 2^127 - 1
+        let p = (1_u64 << 61) - 1;
+        assert_eq!(3_u64.pow_mod(p - 1, p), 1);
+        let p = (1_u128 << 127) - 1;
+        assert_eq!(3_u128.pow_mod(p - 1, p), 1);
+        assert_eq!(u128::MAX.pow_mod(p - 1, p), 1);
+        assert_ne!(3_u128.pow_mod(p - 1, p - 2), 1);
+
+        assert_eq!(2_u32.checked_pow_mod(3, 0), None);
+        assert_eq!(2_i32.checked_pow_mod(-1, 5), None);
+        assert_eq!(2_i32.try_pow_mod(3, -5), Ok(3));
+        assert_eq!((-2_i32).pow_mod(3, -5), 2);
+        assert_eq!(i64::MIN.pow_mod(1, i64::MIN), 0);
+        assert_eq!(i64::MAX.pow_mod(1, i64::MIN), i64::MAX);
+        assert_eq!(2_i32.try_pow_mod(3, 0), Err(IntTraitsError::ZeroInput));
+    }
+
+    #[test]
+    fn pow_mod_random_u128() {
+        let mut state = SEED;
+        for _ in 0..10_000 {
+            let base = xorshift(&mut state);
+            let exp = xorshift(&mut state);
+            let m = xorshift(&mut state) | 1;
+
+            // The ladder used for `u128` agrees with widening for `u64`
+            let r = base.pow_mod(exp, m);
+            assert_eq!((base as u128).pow_mod(exp as u128, m as u128), r as u128, "pow_mod({}, {}, {})", base, exp, m);
+            assert_eq!((base as u128 + m as u128).pow_mod(exp as u128, m as u128), r as u128);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take pow_mod: input is zero")]
+    fn pow_mod_zero_modulus() {
+        let _ = 2_u64.pow_mod(3, 0);
+    }
+
+    macro_rules! check_mod_ops {
+        ($($t:ty),*) => {$(
+            for m in (<$t>::MIN..=<$t>::MAX).filter(|&m| m != 0) {
+                let wide_m = m as i128;
+                for a in <$t>::MIN..=<$t>::MAX {
+                    for b in <$t>::MIN..=<$t>::MAX {
+                        let (wide_a, wide_b) = (a as i128, b as i128);
+                        assert_eq!(a.add_mod(b, m) as i128, (wide_a + wide_b).rem_euclid(wide_m), "add_mod({}, {}, {})", a, b, m);
+                        assert_eq!(a.sub_mod(b, m) as i128, (wide_a - wide_b).rem_euclid(wide_m), "sub_mod({}, {}, {})", a, b, m);
+                        assert_eq!(a.mul_mod(b, m) as i128, (wide_a * wide_b).rem_euclid(wide_m), "mul_mod({}, {}, {})", a, b, m);
+                    }
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    fn mod_ops_exhaustive() {
+        check_mod_ops!(i8, u8);
+    }
+
+    #[test]
+    fn mod_ops_overall() {
+        use core::num::Saturating;
+
+        assert_eq!(u64::MAX.mul_mod(u64::MAX, u64::MAX - 1), 1);
+        assert_eq!((u64::MAX - 1).add_mod(u64::MAX - 2, u64::MAX), u64::MAX - 3);
+        assert_eq!(0_u64.sub_mod(1, u64::MAX), u64::MAX - 1);
+        assert_eq!(u128::MAX.mul_mod(u128::MAX - 1, u128::MAX), 0);
+        assert_eq!((u128::MAX - 1).mul_mod(u128::MAX - 1, u128::MAX), 1);
+        assert_eq!((u128::MAX - 2).add_mod(u128::MAX - 3, u128::MAX - 1), u128::MAX - 4);
+        assert_eq!((1_u128 << 64).mul_mod(1 << 64, u128::MAX), 1);
+        assert_eq!(i64::MIN.mul_mod(i64::MIN, i64::MAX), 1);
+        assert_eq!(i64::MIN.sub_mod(i64::MAX, i64::MAX), i64::MAX - 1);
+        assert_eq!((-7_i32).add_mod(3, 5), 1);
+        assert_eq!(Saturating(250_u8).add_mod(Saturating(10), Saturating(255)), Saturating(5));
+
+        assert_eq!(1_u32.checked_add_mod(1, 0), None);
+        assert_eq!(1_i32.try_sub_mod(2, -3), Ok(2));
+        assert_eq!((-7_i32).add_mod(3, -5), 1);
+        assert_eq!(i64::MIN.mul_mod(3, -1), 0);
+        assert_eq!((i64::MIN + 1).add_mod(-1, i64::MIN), 0);
+        assert_eq!(i64::MAX.sub_mod(i64::MIN, i64::MIN), i64::MAX);
+        assert_eq!(1_i32.try_mul_mod(1, 0), Err(IntTraitsError::ZeroInput));
+    }
+
+    #[test]
+    fn mul_mod_random_u128() {
+        let mut state = SEED;
+        for _ in 0..100_000 {
+            let a = xorshift(&mut state);
+            let b = xorshift(&mut state);
+            let m = xorshift(&mut state).max(1);
+
+            let r = a.mul_mod(b, m);
+            assert_eq!(r as u128, (a as u128 * b as u128) % m as u128);
+            assert_eq!((a as u128).mul_mod(b as u128, m as u128), r as u128, "mul_mod({}, {}, {})", a, b, m);
+        }
+    }
+
+    fn sieve(limit: usize) -> std::vec::Vec<bool> {
+        let mut prime = vec![true; limit];
+        prime[0] = false;
+        prime[1] = false;
+        let mut i = 2;
+        while i * i < limit {
+            if prime[i] {
+                for j in (i * i..limit).step_by(i) {
+                    prime[j] = false;
+                }
+            }
+            i += 1;
+        }
+        prime
+    }
+
+    #[test]
+    fn is_prime_sieve() {
+        let prime = sieve(1 << 22);
+        for n in 0..1_u32 << 22 {
+            assert_eq!(n.is_prime(), prime[n as usize], "is_prime({})", n);
+        }
+        for n in i16::MIN..=i16::MAX {
+            assert_eq!(n.is_prime(), n > 0 && prime[n as usize], "is_prime({})", n);
+        }
+        for n in 0..=u16::MAX {
+            assert_eq!(n.is_prime(), prime[n as usize], "is_prime({})", n);
+            assert_eq!((n as u64).is_prime(), prime[n as usize]);
+            assert_eq!((n as i128).is_prime(), prime[n as usize]);
+        }
+        for n in i8::MIN..=i8::MAX {
+            assert_eq!(n.is_prime(), n > 0 && prime[n as usize], "is_prime({})", n);
+        }
+    }
+
+    #[test]
+    fn is_prime_overall() {
+        use core::num::Wrapping;
+
+        // Strong pseudoprimes to several small bases
+        assert!(!2047_u32.is_prime());
+        assert!(!3_215_031_751_u32.is_prime());
+        assert!(!4_759_123_141_u64.is_prime());
+        assert!(!3_825_123_056_546_413_051_u64.is_prime());
+        assert!(!318_665_857_834_031_151_167_461_u128.is_prime());
+        assert!(!3_317_044_064_679_887_385_961_981_u128.is_prime());
+
+        assert!(!u32::MAX.is_prime());
+        assert!(4_294_967_291_u32.is_prime());
+        assert!(((1_u64 << 61) - 1).is_prime());
+        assert!((u64::MAX - 58).is_prime());
+        assert!(!u64::MAX.is_prime());
+        assert!(!i64::MAX.is_prime());
+        assert!(!i64::MIN.is_prime());
+        assert!(!(-7_i32).is_prime());
+
+        // The smallest primes above 2^64 and below 2^128, and a Mersenne
+        // prime
+        assert!(((1_u128 << 64) + 13).is_prime());
+        assert!((u128::MAX - 158).is_prime());
+        assert!(((1_u128 << 127) - 1).is_prime());
+        assert!(((1_u128 << 89) - 1).is_prime());
+        assert!(((1_i128 << 89) - 1).is_prime());
+        assert!(!((1_u128 << 64) + 11).is_prime());
+        assert!(!u128::MAX.is_prime());
+
+        // Squares and products of large primes
+        let p = (1_u128 << 61) - 1;
+        assert!(!(p * p).is_prime());
+        assert!(!(p * ((1 << 64) + 13)).is_prime());
+        assert!(!(p * 4_294_967_291).is_prime());
+
+        assert!(Wrapping(97_u8).is_prime());
+    }
+
+    #[test]
+    fn baillie_psw_agrees_below_2_64() {
+        // Strong pseudoprimes to base 2 are caught by the Lucas test
+        for &n in [1_373_653_u128, 25_326_001, 3_215_031_751, 2_152_302_898_747].iter() {
+            assert!(super::miller_rabin(n, 2), "{}", n);
+            assert!(!super::strong_lucas(n), "{}", n);
+        }
+
+        // Baillie-PSW agrees with the deterministic Miller-Rabin test below
+        // 2^64
+        let mut state = SEED;
+        for _ in 0..20_000 {
+            let n = xorshift(&mut state) | 1;
+            if super::trial_division(n).is_some() {
+                continue;
+            }
+            let n_128 = n as u128;
+            assert_eq!(super::miller_rabin(n_128, 2) && super::strong_lucas(n_128), n.is_prime(), "is_prime({})", n);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take gcd: result overflows for input: -128")]
+    fn gcd_overflow() {
+        let _ = i8::MIN.gcd(0);
+    }
+
+    #[test]
+    fn nonzero_overall() {
+        use core::num::{NonZeroI32, NonZeroI8, NonZeroU128, NonZeroU64, NonZeroU8};
+        use super::NonZeroIntTraits;
+
+        let max = NonZeroU64::new(u64::MAX).unwrap();
+        assert_eq!(max.sqrt().get(), u32::MAX as u64);
+        assert_eq!(max.cbrt().get(), 2642245);
+        assert_eq!(max.log2(), 63);
+        assert_eq!(max.log10(), 19);
+        assert_eq!(max.log(u64::MAX), 1);
+
+        let one = NonZeroU8::new(1).unwrap();
+        assert_eq!(one.sqrt(), one);
+        assert_eq!(one.cbrt(), one);
+        assert_eq!(one.log2(), 0);
+
+        let max = NonZeroU128::new(u128::MAX).unwrap();
+        assert_eq!(max.sqrt().get(), u64::MAX as u128);
+        assert_eq!(max.log10(), 38);
+
+        let n = NonZeroI32::new(-28).unwrap();
+        assert_eq!(n.cbrt().get(), -4);
+        let n = NonZeroI8::new(-1).unwrap();
+        assert_eq!(n.cbrt().get(), -1);
+        let n = NonZeroI32::new(1000).unwrap();
+        assert_eq!(n.log10(), 3);
+        assert_eq!(n.sqrt().get(), 31);
+    }
+
+    #[test]
+    #[should_panic(expected = "input is negative: -4")]
+    fn nonzero_negative_sqrt() {
+        use core::num::NonZeroI32;
+        use super::NonZeroIntTraits;
+
+        let _ = NonZeroI32::new(-4).unwrap().sqrt();
+    }
+
+    #[test]
+    #[should_panic(expected = "input is negative: -1000")]
+    fn nonzero_negative_log() {
+        use core::num::NonZeroI64;
+        use super::NonZeroIntTraits;
+
+        let _ = NonZeroI64::new(-1000).unwrap().log10();
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot take log: base is less than 2: 1")]
+    fn nonzero_invalid_base() {
+        use core::num::NonZeroU32;
+        use super::NonZeroIntTraits;
+
+        let _ = NonZeroU32::new(1000).unwrap().log(1);
+    }
+
+    // The baseline style of generic code, bounded on `IntTraits` alone
+    fn generic_int_traits<T: IntTraits<T>>(n: T) -> (T, T, T, bool) {
+        (n.sqrt(), n.log2(), n.gcd(n), n.is_prime())
+    }
+
+    #[test]
+    fn int_traits_generic() {
+        use core::num::Wrapping;
+
+        assert_eq!(generic_int_traits(97_u32), (9, 6, 97, true));
+        assert_eq!(generic_int_traits(1000_i64), (31, 9, 1000, false));
+        assert_eq!(generic_int_traits(Wrapping(64_u8)), (Wrapping(8), Wrapping(6), Wrapping(64), false));
+    }
+
+    mod primitive {
+        use PrimitiveInt;
+
+        // Counts the digits of `n` in the given base, written once for every
+        // type.
+        fn digits<T: PrimitiveInt>(mut n: T, base: T) -> u32 {
+            let mut count = 1;
+            while n.checked_div(base).is_some_and(|q| q != T::ZERO) {
+                n = n / base;
+                count += 1;
+            }
+            count
+        }
+
+        fn bounds<T: PrimitiveInt>() -> (T, T, u32, bool) {
+            (T::MIN, T::MAX, T::BITS, T::SIGNED)
+        }
+
+        #[test]
+        fn primitive_generic() {
+            assert_eq!(digits(255_u8, 10), 3);
+            assert_eq!(digits(-1000_i16, 10), 4);
+            assert_eq!(digits(u128::MAX, 2), 128);
+            assert_eq!(digits(0_usize, 10), 1);
+
+            assert_eq!(bounds::<i8>(), (-128, 127, 8, true));
+            assert_eq!(bounds::<u64>(), (0, u64::MAX, 64, false));
+            assert_eq!(<u32 as PrimitiveInt>::ZERO + <u32 as PrimitiveInt>::ONE, 1);
+        }
+
+        #[test]
+        fn primitive_checked() {
+            assert_eq!(PrimitiveInt::checked_add(u8::MAX, 1), None);
+            assert_eq!(PrimitiveInt::checked_sub(0_u16, 1), None);
+            assert_eq!(PrimitiveInt::checked_mul(i32::MIN, -1), None);
+            assert_eq!(PrimitiveInt::checked_div(1_i64, 0), None);
+            assert_eq!(PrimitiveInt::checked_rem(i128::MIN, -1), None);
+            assert_eq!(PrimitiveInt::checked_neg(isize::MIN), None);
+            assert_eq!(PrimitiveInt::checked_pow(3_u64, 40), Some(3_u64.pow(40)));
+            assert_eq!(PrimitiveInt::checked_pow(3_u64, 41), None);
+            assert_eq!(PrimitiveInt::trailing_zeros(40_u8), 3);
+            assert_eq!(PrimitiveInt::leading_zeros(1_u128), 127);
+        }
+    }
+
+    mod focused {
+        use core::num::Wrapping;
+        use {IntCbrt, IntLog, IntRoot, IntSqrt};
+
+        fn generic_sqrt<T: IntSqrt>(n: T) -> T::Output {
+            n.sqrt()
+        }
+
+        fn generic_root_log<T: IntRoot<Output = T> + IntLog>(n: T) -> <T as IntLog>::Output {
+            n.nth_root(3).log2()
+        }
+
+        #[test]
+        fn focused_traits_generic() {
+            assert_eq!(generic_sqrt(63_u8), 7);
+            assert_eq!(generic_sqrt(u128::MAX), u64::MAX as u128);
+            assert_eq!(generic_sqrt(Wrapping(63_i64)), Wrapping(7));
+            assert_eq!(generic_root_log(1_u32 << 30), 10);
+            assert_eq!(generic_root_log(Wrapping(1_000_000_i32)), Wrapping(6));
+        }
+
+        #[test]
+        fn focused_traits_in_scope() {
+            assert_eq!(63_u32.sqrt(), 7);
+            assert_eq!(63_u32.sqrt_rem(), (7, 14));
+            assert!(49_u32.is_square());
+            assert_eq!((-28_i32).cbrt(), -4);
+            assert_eq!(81_u64.nth_root(4), 3);
+            assert_eq!(64_u64.perfect_power(), Some((2, 6)));
+            assert_eq!(1000_i16.log10(), 3);
+            assert_eq!(0_u8.checked_log2(), None);
+        }
+    }
+
+    #[test]
+    fn wrapper_overall() {
+        use core::num::{Saturating, Wrapping};
+
+        assert_eq!(Wrapping(63_u32).sqrt(), Wrapping(7));
+        assert_eq!(Wrapping(u64::MAX).sqrt_rem(), (Wrapping(u32::MAX as u64), Wrapping(2 * u32::MAX as u64)));
+        assert_eq!(Wrapping(-28_i32).cbrt(), Wrapping(-4));
+        assert_eq!(Wrapping(1000_i16).log10(), Wrapping(3));
+        assert_eq!(Wrapping(64_u8).perfect_power(), Some((Wrapping(2), 6)));
+        assert!(Wrapping(49_u16).is_square());
+
+        assert_eq!(Saturating(u128::MAX).sqrt_ceil(), Saturating(1 << 64));
+        assert_eq!(Saturating(-28_i64).cbrt_ceil(), Saturating(-3));
+        assert_eq!(Saturating(1025_usize).log2_ceil(), Saturating(11));
+        assert_eq!(Saturating(-27_i8).nth_root(3), Saturating(-3));
+        assert!(Saturating(27_i32).is_perfect_cube());
+
+        assert_eq!(Wrapping(-4_i32).try_sqrt(), Err(IntTraitsError::NegativeInput(Wrapping(-4))));
+        assert_eq!(Saturating(0_u32).checked_log2(), None);
+        assert_eq!(Saturating(8_u32).try_log(1), Err(IntTraitsError::InvalidBase(1)));
+    }
+
+    #[test]
+    #[should_panic(expected = "input is negative: -4")]
+    fn wrapper_negative_sqrt() {
+        let _ = ::core::num::Saturating(-4_i32).sqrt();
+    }
+
+    #[test]
+    fn log_overall() {
+        assert_eq!(1000_u32.log(10), 3);
+        assert_eq!(999_i32.log(10), 2);
+        assert_eq!(1000_u16.log10(), 3);
+        assert_eq!(1024_i64.log2(), 10);
+        assert_eq!(u64::MAX.log10(), 19);
+        assert_eq!(u64::MAX.log2(), 63);
+        assert_eq!(u64::MAX.log(u64::MAX), 1);
+        assert_eq!(i64::MAX.log(3), 39);
+        assert_eq!(200_u8.log(300), 0);
+        assert_eq!(i8::MAX.log(u64::MAX), 0);
+        assert_eq!(u128::MAX.log10(), 38);
+        assert_eq!(u128::MAX.log2(), 127);
+        assert_eq!(u128::MAX.log(u64::MAX), 2);
+        assert_eq!((1_u128 << 64).log(u64::MAX), 1);
+        assert_eq!(i128::MAX.log2(), 126);
+    }
+
+    macro_rules! check_log_powers {
+        ($($t:ty),*) => {$(
+            for base in 2..=36 {
+                let mut p: $t = 1;
+                let mut k = 0;
+                loop {
+                    assert_eq!(p.log(base), k, "{}.log({})", p, base);
+                    if k > 0 {
+                        assert_eq!((p - 1).log(base), k - 1, "{}.log({})", p - 1, base);
+                    }
+
+                    match p.checked_mul(base as $t) {
+                        Some(next) => p = next,
+                        None => break,
+                    }
+                    k += 1;
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    fn log_powers() {
+        check_log_powers!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+    }
+
+    #[test]
+    fn log2_exhaustive() {
+        for n in 1..=u16::MAX {
+            let mut log = 0;
+            while n as u32 >> (log + 1) != 0 {
+                log += 1;
+            }
+            assert_eq!(n.log2(), log, "log2({})", n);
+            assert_eq!((n as i16).checked_log2(), (n as i16 > 0).then_some(log as i16));
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "base is less than 2: ")]
+    fn zero_base_log() {
+        let _ = 50_u32.log(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "base is less than 2: ")]
+    fn unit_base_log() {
+        let _ = 50_i32.log(1);
+    }
+
+    #[test]
+    fn checked_log_edge_cases() {
+        // Invalid bases
+        assert_eq!(50_u32.checked_log(0), None);
+        assert_eq!(50_u32.checked_log(1), None);
+        assert_eq!(50_i32.checked_log(0), None);
+        assert_eq!(50_i32.checked_log(1), None);
+
+        // Invalid values
+        assert_eq!(0_u32.checked_log(10), None);
+        assert_eq!(0_i32.checked_log(10), None);
+        assert_eq!((-50_i32).checked_log(10), None);
+
+        // Base larger than the value
+        assert_eq!(50_u32.checked_log(51), Some(0));
+        assert_eq!(1_u32.checked_log(2), Some(0));
+        assert_eq!(50_i8.checked_log(1000), Some(0));
+
+        // Base equal to the value
+        assert_eq!(50_u32.checked_log(50), Some(1));
+        assert_eq!(i64::MAX.checked_log(i64::MAX as u64), Some(1));
+    }
+
+    macro_rules! check_checked_agrees {
+        ($($t:ty),*) => {$(
+            for n in <$t>::MIN..=<$t>::MAX {
+                if n < 0 {
+                    assert_eq!(n.checked_sqrt(), None);
+                } else {
+                    assert_eq!(n.checked_sqrt(), Some(n.sqrt()));
+                }
+                assert_eq!(n.checked_cbrt(), Some(n.cbrt()));
+
+                if n <= 0 {
+                    assert_eq!(n.checked_log2(), None);
+                    assert_eq!(n.checked_log10(), None);
+                } else {
+                    assert_eq!(n.checked_log2(), Some(n.log2()));
+                    assert_eq!(n.checked_log10(), Some(n.log10()));
+                }
+            }
+        )*};
+    }
+
+    #[test]
+    #[allow(unused_comparisons)]
+    fn checked_agrees_with_panicking() {
+        check_checked_agrees!(i8, i16, u8, u16);
+    }
+
+    #[test]
+    fn checked_overall() {
+        assert_eq!(63_u64.checked_sqrt(), Some(7));
+        assert_eq!((-63_i64).checked_sqrt(), None);
+        assert_eq!(i128::MIN.checked_sqrt(), None);
+        assert_eq!(u128::MAX.checked_sqrt(), Some(u64::MAX as u128));
+        assert_eq!(891_isize.checked_cbrt(), Some(9));
+        assert_eq!((-891_isize).checked_cbrt(), Some(-10));
+        assert_eq!(1000_u32.checked_log10(), Some(3));
+        assert_eq!(0_u32.checked_log10(), None);
+        assert_eq!(1024_i16.checked_log2(), Some(10));
+        assert_eq!((-1024_i16).checked_log2(), None);
+    }
+
+    #[test]
+    fn try_errors() {
+        assert_eq!(63_u32.try_sqrt(), Ok(7));
+        assert_eq!((-63_i32).try_sqrt(), Err(IntTraitsError::NegativeInput(-63)));
+        assert_eq!(i128::MIN.try_cbrt(), Ok(-5541191377757));
+        assert_eq!(1000_i16.try_log10(), Ok(3));
+        assert_eq!(0_u8.try_log2(), Err(IntTraitsError::ZeroInput));
+        assert_eq!(0_i8.try_log2(), Err(IntTraitsError::ZeroInput));
+        assert_eq!((-1_i64).try_log(10), Err(IntTraitsError::NegativeInput(-1)));
+        assert_eq!(50_i64.try_log(1), Err(IntTraitsError::InvalidBase(1)));
+        assert_eq!(50_u64.try_log(0), Err(IntTraitsError::InvalidBase(0)));
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(IntTraitsError::NegativeInput(-5).to_string(), "input is negative: -5");
+        assert_eq!(IntTraitsError::ZeroInput::<u8>.to_string(), "input is zero");
+        assert_eq!(IntTraitsError::InvalidBase::<u8>(1).to_string(), "base is less than 2: 1");
+        assert_eq!(IntTraitsError::Overflow(255_u8).to_string(), "result overflows for input: 255");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn error_is_std_error() {
+        fn assert_error<E: ::std::error::Error>() {}
+        assert_error::<IntTraitsError<u8>>();
+    }
+
+    #[test]
+    #[should_panic(expected = "input is negative: -4")]
+    fn signed_less_zero_sqrt() {
+        let _ = (-4_i32).sqrt();
     }
 
     #[test]
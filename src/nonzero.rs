@@ -0,0 +1,122 @@
+//! Implementations for the `NonZero` integer types.
+//!
+//! These cannot be zero, so logarithms never fail on them and roots are known
+//! to be non-zero as well.
+
+use core::num::{NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize};
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+
+use IntTraitsError;
+
+/// Provides the functions of `IntTraits` on the `NonZero` integer types.
+///
+/// Roots are returned as the same `NonZero` type, while logarithms, which may
+/// be zero, are returned as the underlying integer type.
+pub trait NonZeroIntTraits: Sized {
+    /// The underlying integer type.
+    type Int;
+
+    /// Takes the floored square root of a number.
+    ///
+    /// ## Panics
+    /// Panics if `n` is negative.
+    fn sqrt(self) -> Self;
+
+    /// Takes the floored cubic root of a number.
+    ///
+    /// The cubic root of a negative number is floored toward negative
+    /// infinity.
+    fn cbrt(self) -> Self;
+
+    /// Returns the floored logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `self` is negative or if the base `n` is less than 2.
+    fn log(self, n: u64) -> Self::Int;
+
+    /// Returns the floored base 10 logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `n` is negative.
+    fn log10(self) -> Self::Int {
+        self.log(10)
+    }
+
+    /// Returns the floored base 2 logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `n` is negative.
+    fn log2(self) -> Self::Int {
+        self.log(2)
+    }
+}
+
+// The roots of a non-zero value are non-zero, and a logarithm only requires
+// its input to be non-zero, so these call the constant functions directly
+// rather than going through the checks of `IntTraits`.
+macro_rules! impl_nonzero_trait {
+    ($nz:ident, $t:ident) => {
+        impl NonZeroIntTraits for $nz {
+            type Int = $t;
+
+            fn sqrt(self) -> $nz {
+                $nz::new(::$t::sqrt(self.get())).expect("root of a non-zero value is non-zero")
+            }
+
+            fn cbrt(self) -> $nz {
+                $nz::new(::$t::cbrt(self.get())).expect("root of a non-zero value is non-zero")
+            }
+
+            fn log(self, n: u64) -> $t {
+                if n < 2 {
+                    panic!("cannot take log: {}", IntTraitsError::<$t>::InvalidBase(n));
+                }
+                ::$t::log_nonzero(self.get(), n)
+            }
+        }
+    };
+    ($nz:ident, $t:ident, $ut:ident) => {
+        impl NonZeroIntTraits for $nz {
+            type Int = $t;
+
+            fn sqrt(self) -> $nz {
+                let n = self.get();
+                if n < 0 {
+                    panic!("cannot take sqrt: {}", IntTraitsError::NegativeInput(n));
+                }
+                $nz::new(::$ut::sqrt(n as $ut) as $t).expect("root of a non-zero value is non-zero")
+            }
+
+            fn cbrt(self) -> $nz {
+                // The root of a negative value is floored toward negative
+                // infinity, so is at most -1.
+                $nz::new(::$t::cbrt(self.get())).expect("root of a non-zero value is non-zero")
+            }
+
+            fn log(self, n: u64) -> $t {
+                let m = self.get();
+                if m < 0 {
+                    panic!("cannot take log: {}", IntTraitsError::NegativeInput(m));
+                }
+                if n < 2 {
+                    panic!("cannot take log: {}", IntTraitsError::<$t>::InvalidBase(n));
+                }
+                ::$t::log_nonzero(m, n)
+            }
+        }
+    };
+}
+
+impl_nonzero_trait!(NonZeroI8, i8, u8);
+impl_nonzero_trait!(NonZeroI16, i16, u16);
+impl_nonzero_trait!(NonZeroI32, i32, u32);
+impl_nonzero_trait!(NonZeroI64, i64, u64);
+impl_nonzero_trait!(NonZeroI128, i128, u128);
+impl_nonzero_trait!(NonZeroIsize, isize, usize);
+
+impl_nonzero_trait!(NonZeroU8, u8);
+impl_nonzero_trait!(NonZeroU16, u16);
+impl_nonzero_trait!(NonZeroU32, u32);
+impl_nonzero_trait!(NonZeroU64, u64);
+impl_nonzero_trait!(NonZeroU128, u128);
+impl_nonzero_trait!(NonZeroUsize, usize);
@@ -0,0 +1,140 @@
+//! A common interface over the primitive integer types.
+
+use core::fmt;
+use core::hash::Hash;
+use core::ops::{Add, BitAnd, BitOr, BitXor, Div, Mul, Not, Rem, Shl, Shr, Sub};
+
+/// The operations shared by every primitive integer type.
+///
+/// This allows algorithms to be written once over all integer types, rather
+/// than once per type inside a macro. Only the checked forms of the fallible
+/// operations are provided, as the plain operators panic or wrap depending on
+/// the build profile.
+pub trait PrimitiveInt:
+    Copy
+    + Eq
+    + Ord
+    + Hash
+    + Default
+    + fmt::Debug
+    + fmt::Display
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+    + Shl<u32, Output = Self>
+    + Shr<u32, Output = Self>
+    + BitAnd<Output = Self>
+    + BitOr<Output = Self>
+    + BitXor<Output = Self>
+    + Not<Output = Self>
+{
+    /// The value `0`.
+    const ZERO: Self;
+
+    /// The value `1`.
+    const ONE: Self;
+
+    /// The smallest value of the type.
+    const MIN: Self;
+
+    /// The largest value of the type.
+    const MAX: Self;
+
+    /// The width of the type in bits.
+    const BITS: u32;
+
+    /// Whether the type is signed.
+    const SIGNED: bool;
+
+    /// Checked addition, returning `None` on overflow.
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+
+    /// Checked subtraction, returning `None` on overflow.
+    fn checked_sub(self, rhs: Self) -> Option<Self>;
+
+    /// Checked multiplication, returning `None` on overflow.
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Checked division, returning `None` if `rhs` is zero or on overflow.
+    fn checked_div(self, rhs: Self) -> Option<Self>;
+
+    /// Checked remainder, returning `None` if `rhs` is zero or on overflow.
+    fn checked_rem(self, rhs: Self) -> Option<Self>;
+
+    /// Checked negation, returning `None` on overflow.
+    fn checked_neg(self) -> Option<Self>;
+
+    /// Checked exponentiation, returning `None` on overflow.
+    fn checked_pow(self, exp: u32) -> Option<Self>;
+
+    /// Returns the number of trailing zeros in the binary representation.
+    fn trailing_zeros(self) -> u32;
+
+    /// Returns the number of leading zeros in the binary representation.
+    fn leading_zeros(self) -> u32;
+}
+
+macro_rules! impl_primitive_int {
+    ($t:ident, $signed:expr) => {
+        impl PrimitiveInt for $t {
+            const ZERO: $t = 0;
+            const ONE: $t = 1;
+            const MIN: $t = $t::MIN;
+            const MAX: $t = $t::MAX;
+            const BITS: u32 = $t::BITS;
+            const SIGNED: bool = $signed;
+
+            fn checked_add(self, rhs: $t) -> Option<$t> {
+                $t::checked_add(self, rhs)
+            }
+
+            fn checked_sub(self, rhs: $t) -> Option<$t> {
+                $t::checked_sub(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: $t) -> Option<$t> {
+                $t::checked_mul(self, rhs)
+            }
+
+            fn checked_div(self, rhs: $t) -> Option<$t> {
+                $t::checked_div(self, rhs)
+            }
+
+            fn checked_rem(self, rhs: $t) -> Option<$t> {
+                $t::checked_rem(self, rhs)
+            }
+
+            fn checked_neg(self) -> Option<$t> {
+                $t::checked_neg(self)
+            }
+
+            fn checked_pow(self, exp: u32) -> Option<$t> {
+                $t::checked_pow(self, exp)
+            }
+
+            fn trailing_zeros(self) -> u32 {
+                $t::trailing_zeros(self)
+            }
+
+            fn leading_zeros(self) -> u32 {
+                $t::leading_zeros(self)
+            }
+        }
+    };
+}
+
+impl_primitive_int!(u8, false);
+impl_primitive_int!(u16, false);
+impl_primitive_int!(u32, false);
+impl_primitive_int!(u64, false);
+impl_primitive_int!(u128, false);
+impl_primitive_int!(usize, false);
+
+impl_primitive_int!(i8, true);
+impl_primitive_int!(i16, true);
+impl_primitive_int!(i32, true);
+impl_primitive_int!(i64, true);
+impl_primitive_int!(i128, true);
+impl_primitive_int!(isize, true);
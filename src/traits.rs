@@ -0,0 +1,590 @@
+//! The traits for each family of functions.
+//!
+//! Every panicking function has a `checked_` counterpart which returns `None`
+//! and a `try_` counterpart which returns an `IntTraitsError` in place of
+//! panicking. Both are implemented in terms of the `try_` function so all
+//! three always agree on which inputs are valid.
+
+use core::fmt;
+
+use IntTraitsError;
+
+/// Square roots of integers.
+pub trait IntSqrt: Sized + Copy + fmt::Display {
+    /// The type of the result, which is `Self` for every implementation in
+    /// this crate.
+    type Output;
+
+    /// Takes the floored square root of a number.
+    ///
+    /// ## Panics
+    /// Panics if `n` is negative.
+    fn sqrt(self) -> Self::Output {
+        match self.try_sqrt() {
+            Ok(root) => root,
+            Err(e) => panic!("cannot take sqrt: {}", e),
+        }
+    }
+
+    /// Takes the floored square root of a number, or `None` if `n` is
+    /// negative.
+    fn checked_sqrt(self) -> Option<Self::Output> {
+        self.try_sqrt().ok()
+    }
+
+    /// Takes the floored square root of a number.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` if `n` is negative.
+    fn try_sqrt(self) -> Result<Self::Output, IntTraitsError<Self>> {
+        self.try_sqrt_rem().map(|(root, _)| root)
+    }
+
+    /// Takes the floored square root `r` of a number along with the
+    /// remainder `n - r^2`.
+    ///
+    /// ## Panics
+    /// Panics if `n` is negative.
+    fn sqrt_rem(self) -> (Self::Output, Self::Output) {
+        match self.try_sqrt_rem() {
+            Ok(root) => root,
+            Err(e) => panic!("cannot take sqrt: {}", e),
+        }
+    }
+
+    /// Takes the floored square root `r` of a number along with the
+    /// remainder `n - r^2`, or `None` if `n` is negative.
+    fn checked_sqrt_rem(self) -> Option<(Self::Output, Self::Output)> {
+        self.try_sqrt_rem().ok()
+    }
+
+    /// Takes the floored square root `r` of a number along with the
+    /// remainder `n - r^2`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` if `n` is negative.
+    fn try_sqrt_rem(self) -> Result<(Self::Output, Self::Output), IntTraitsError<Self>>;
+
+    /// Takes the ceiling of the square root of a number.
+    ///
+    /// ## Panics
+    /// Panics if `n` is negative.
+    fn sqrt_ceil(self) -> Self::Output {
+        match self.try_sqrt_ceil() {
+            Ok(root) => root,
+            Err(e) => panic!("cannot take sqrt: {}", e),
+        }
+    }
+
+    /// Takes the ceiling of the square root of a number, or `None` if `n` is
+    /// negative.
+    fn checked_sqrt_ceil(self) -> Option<Self::Output> {
+        self.try_sqrt_ceil().ok()
+    }
+
+    /// Takes the ceiling of the square root of a number.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` if `n` is negative.
+    fn try_sqrt_ceil(self) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns whether `n` is the square of an integer.
+    ///
+    /// Most non-squares are rejected by a few table lookups before any root
+    /// is taken, which makes this considerably faster than comparing the
+    /// square of `sqrt` against `n` in hot loops such as Fermat factorization.
+    fn is_square(self) -> bool;
+
+    /// Returns whether `n` is the square of an integer.
+    ///
+    /// This is the same as `is_square`.
+    fn is_perfect_square(self) -> bool {
+        self.is_square()
+    }
+}
+
+/// Cubic roots of integers.
+pub trait IntCbrt: Sized + Copy + fmt::Display {
+    /// The type of the result, which is `Self` for every implementation in
+    /// this crate.
+    type Output;
+
+    /// Takes the floored cubic root of a number.
+    ///
+    /// The cubic root of a negative number is floored toward negative
+    /// infinity, so `(-28).cbrt() == -4`.
+    fn cbrt(self) -> Self::Output {
+        match self.try_cbrt() {
+            Ok(root) => root,
+            Err(e) => panic!("cannot take cbrt: {}", e),
+        }
+    }
+
+    /// Takes the floored cubic root of a number.
+    ///
+    /// Every integer has a cubic root so this never returns `None`. It is
+    /// provided for uniformity with the other functions.
+    fn checked_cbrt(self) -> Option<Self::Output> {
+        self.try_cbrt().ok()
+    }
+
+    /// Takes the floored cubic root of a number.
+    ///
+    /// Every integer has a cubic root so this never returns an error. It is
+    /// provided for uniformity with the other functions.
+    fn try_cbrt(self) -> Result<Self::Output, IntTraitsError<Self>> {
+        self.try_cbrt_rem().map(|(root, _)| root)
+    }
+
+    /// Takes the floored cubic root `r` of a number along with the remainder
+    /// `n - r^3`.
+    ///
+    /// As the root is floored the remainder is never negative, so
+    /// `(-28).cbrt_rem() == (-4, 36)`.
+    fn cbrt_rem(self) -> (Self::Output, Self::Output) {
+        match self.try_cbrt_rem() {
+            Ok(root) => root,
+            Err(e) => panic!("cannot take cbrt: {}", e),
+        }
+    }
+
+    /// Takes the floored cubic root `r` of a number along with the remainder
+    /// `n - r^3`. This never returns `None`.
+    fn checked_cbrt_rem(self) -> Option<(Self::Output, Self::Output)> {
+        self.try_cbrt_rem().ok()
+    }
+
+    /// Takes the floored cubic root `r` of a number along with the remainder
+    /// `n - r^3`. This never returns an error.
+    fn try_cbrt_rem(self) -> Result<(Self::Output, Self::Output), IntTraitsError<Self>>;
+
+    /// Takes the ceiling of the cubic root of a number.
+    ///
+    /// The cubic root of a negative number is rounded toward positive
+    /// infinity, so `(-28).cbrt_ceil() == -3`.
+    fn cbrt_ceil(self) -> Self::Output {
+        match self.try_cbrt_ceil() {
+            Ok(root) => root,
+            Err(e) => panic!("cannot take cbrt: {}", e),
+        }
+    }
+
+    /// Takes the ceiling of the cubic root of a number. This never returns
+    /// `None`.
+    fn checked_cbrt_ceil(self) -> Option<Self::Output> {
+        self.try_cbrt_ceil().ok()
+    }
+
+    /// Takes the ceiling of the cubic root of a number. This never returns an
+    /// error.
+    fn try_cbrt_ceil(self) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns whether `n` is the cube of an integer.
+    fn is_perfect_cube(self) -> bool;
+}
+
+/// Roots of arbitrary degree and perfect powers of integers.
+pub trait IntRoot: Sized + Copy + fmt::Display {
+    /// The type of the result, which is `Self` for every implementation in
+    /// this crate.
+    type Output;
+
+    /// Takes the floored `k`-th root of a number.
+    ///
+    /// Odd roots of negative numbers are floored toward negative infinity,
+    /// so `(-28).nth_root(3) == -4`.
+    ///
+    /// ## Panics
+    /// Panics if `k` is zero, or if `n` is negative and `k` is even.
+    fn nth_root(self, k: u32) -> Self::Output {
+        match self.try_nth_root(k) {
+            Ok(root) => root,
+            Err(e) => panic!("cannot take nth_root: {}", e),
+        }
+    }
+
+    /// Takes the floored `k`-th root of a number, or `None` if `k` is zero, or
+    /// if `n` is negative and `k` is even.
+    fn checked_nth_root(self, k: u32) -> Option<Self::Output> {
+        self.try_nth_root(k).ok()
+    }
+
+    /// Takes the floored `k`-th root of a number.
+    ///
+    /// ## Errors
+    /// Returns `InvalidDegree` if `k` is zero, and `NegativeInput` if `n` is
+    /// negative and `k` is even.
+    fn try_nth_root(self, k: u32) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns the smallest base `b` and largest exponent `k >= 2` such that
+    /// `b^k == n`, or `None` if there is no such pair.
+    ///
+    /// Negative numbers only have odd exponents, so
+    /// `(-64).perfect_power() == Some((-4, 3))`. As 0 and 1 are powers of
+    /// themselves for every exponent they return `None`, as does -1.
+    fn perfect_power(self) -> Option<(Self::Output, u32)>;
+}
+
+/// Logarithms of integers in an integer base.
+pub trait IntLog: Sized + Copy + fmt::Display {
+    /// The type of the result, which is `Self` for every implementation in
+    /// this crate.
+    type Output;
+
+    /// Returns the floored logarithm of `n`.
+    ///
+    /// The logarithm must be of integer base. This is to avoid unnecessary
+    /// casts and is purely ergonomic.
+    ///
+    /// ## Panics
+    /// Panics if `self` <= 0 or if the base `n` is less than 2.
+    fn log(self, n: u64) -> Self::Output {
+        match self.try_log(n) {
+            Ok(log) => log,
+            Err(e) => panic!("cannot take log: {}", e),
+        }
+    }
+
+    /// Returns the floored logarithm of `n`, or `None` if `self` <= 0 or the
+    /// base `n` is less than 2.
+    fn checked_log(self, n: u64) -> Option<Self::Output> {
+        self.try_log(n).ok()
+    }
+
+    /// Returns the floored logarithm of `n`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` or `ZeroInput` if `self` <= 0, and
+    /// `InvalidBase` if the base `n` is less than 2.
+    fn try_log(self, n: u64) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns the ceiling of the logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `self` <= 0 or if the base `n` is less than 2.
+    fn log_ceil(self, n: u64) -> Self::Output {
+        match self.try_log_ceil(n) {
+            Ok(log) => log,
+            Err(e) => panic!("cannot take log: {}", e),
+        }
+    }
+
+    /// Returns the ceiling of the logarithm of `n`, or `None` if `self` <= 0
+    /// or the base `n` is less than 2.
+    fn checked_log_ceil(self, n: u64) -> Option<Self::Output> {
+        self.try_log_ceil(n).ok()
+    }
+
+    /// Returns the ceiling of the logarithm of `n`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` or `ZeroInput` if `self` <= 0, and
+    /// `InvalidBase` if the base `n` is less than 2.
+    fn try_log_ceil(self, n: u64) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns the floored base 10 logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `n` <= 0.
+    fn log10(self) -> Self::Output {
+        self.log(10)
+    }
+
+    /// Returns the floored base 10 logarithm of `n`, or `None` if `n` <= 0.
+    fn checked_log10(self) -> Option<Self::Output> {
+        self.checked_log(10)
+    }
+
+    /// Returns the floored base 10 logarithm of `n`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` or `ZeroInput` if `n` <= 0.
+    fn try_log10(self) -> Result<Self::Output, IntTraitsError<Self>> {
+        self.try_log(10)
+    }
+
+    /// Returns the ceiling of the base 10 logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `n` <= 0.
+    fn log10_ceil(self) -> Self::Output {
+        self.log_ceil(10)
+    }
+
+    /// Returns the ceiling of the base 10 logarithm of `n`, or `None` if
+    /// `n` <= 0.
+    fn checked_log10_ceil(self) -> Option<Self::Output> {
+        self.checked_log_ceil(10)
+    }
+
+    /// Returns the ceiling of the base 10 logarithm of `n`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` or `ZeroInput` if `n` <= 0.
+    fn try_log10_ceil(self) -> Result<Self::Output, IntTraitsError<Self>> {
+        self.try_log_ceil(10)
+    }
+
+    /// Returns the floored base 2 logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `n` <= 0.
+    fn log2(self) -> Self::Output {
+        self.log(2)
+    }
+
+    /// Returns the floored base 2 logarithm of `n`, or `None` if `n` <= 0.
+    fn checked_log2(self) -> Option<Self::Output> {
+        self.checked_log(2)
+    }
+
+    /// Returns the floored base 2 logarithm of `n`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` or `ZeroInput` if `n` <= 0.
+    fn try_log2(self) -> Result<Self::Output, IntTraitsError<Self>> {
+        self.try_log(2)
+    }
+
+    /// Returns the ceiling of the base 2 logarithm of `n`.
+    ///
+    /// ## Panics
+    /// Panics if `n` <= 0.
+    fn log2_ceil(self) -> Self::Output {
+        self.log_ceil(2)
+    }
+
+    /// Returns the ceiling of the base 2 logarithm of `n`, or `None` if
+    /// `n` <= 0.
+    fn checked_log2_ceil(self) -> Option<Self::Output> {
+        self.checked_log_ceil(2)
+    }
+
+    /// Returns the ceiling of the base 2 logarithm of `n`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` or `ZeroInput` if `n` <= 0.
+    fn try_log2_ceil(self) -> Result<Self::Output, IntTraitsError<Self>> {
+        self.try_log_ceil(2)
+    }
+}
+
+/// Greatest common divisors and least common multiples of integers.
+pub trait IntGcd: Sized + Copy + fmt::Display {
+    /// The type of the result, which is `Self` for every implementation in
+    /// this crate.
+    type Output;
+
+    /// The type of the Bézout coefficients returned by `extended_gcd`, which
+    /// is the signed type of the same width.
+    type Coefficient;
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    ///
+    /// The result is never negative, and `gcd(0, 0) == 0`.
+    ///
+    /// ## Panics
+    /// Panics if the result does not fit in the type, which only happens for
+    /// signed types when both inputs are `MIN` or zero.
+    fn gcd(self, other: Self) -> Self::Output {
+        match self.try_gcd(other) {
+            Ok(gcd) => gcd,
+            Err(e) => panic!("cannot take gcd: {}", e),
+        }
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`, or `None`
+    /// if it does not fit in the type.
+    fn checked_gcd(self, other: Self) -> Option<Self::Output> {
+        self.try_gcd(other).ok()
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`.
+    ///
+    /// ## Errors
+    /// Returns `Overflow` if the result does not fit in the type.
+    fn try_gcd(self, other: Self) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns the least common multiple of `self` and `other`.
+    ///
+    /// The result is never negative, and is zero if either input is zero.
+    ///
+    /// ## Panics
+    /// Panics if the result does not fit in the type.
+    fn lcm(self, other: Self) -> Self::Output {
+        match self.try_lcm(other) {
+            Ok(lcm) => lcm,
+            Err(e) => panic!("cannot take lcm: {}", e),
+        }
+    }
+
+    /// Returns the least common multiple of `self` and `other`, or `None` if
+    /// it does not fit in the type.
+    fn checked_lcm(self, other: Self) -> Option<Self::Output> {
+        self.try_lcm(other).ok()
+    }
+
+    /// Returns the least common multiple of `self` and `other`.
+    ///
+    /// ## Errors
+    /// Returns `Overflow` if the result does not fit in the type.
+    fn try_lcm(self, other: Self) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns the greatest common divisor `g` of `self` and `other` along
+    /// with Bézout coefficients `x` and `y` such that
+    /// `self * x + other * y == g`.
+    ///
+    /// The coefficients are those found by the extended Euclidean algorithm,
+    /// which are no larger in magnitude than half of the other input divided
+    /// by `g`, so they always fit in the signed type of the same width.
+    ///
+    /// ## Panics
+    /// Panics if `g` does not fit in the type, as for `gcd`.
+    fn extended_gcd(self, other: Self) -> (Self::Output, Self::Coefficient, Self::Coefficient) {
+        match self.try_extended_gcd(other) {
+            Ok(result) => result,
+            Err(e) => panic!("cannot take extended gcd: {}", e),
+        }
+    }
+
+    /// Returns the greatest common divisor `g` of `self` and `other` along
+    /// with Bézout coefficients `x` and `y` such that
+    /// `self * x + other * y == g`, or `None` if `g` does not fit in the
+    /// type.
+    fn checked_extended_gcd(
+        self,
+        other: Self,
+    ) -> Option<(Self::Output, Self::Coefficient, Self::Coefficient)> {
+        self.try_extended_gcd(other).ok()
+    }
+
+    /// Returns the greatest common divisor `g` of `self` and `other` along
+    /// with Bézout coefficients `x` and `y` such that
+    /// `self * x + other * y == g`.
+    ///
+    /// ## Errors
+    /// Returns `Overflow` if `g` does not fit in the type.
+    #[allow(clippy::type_complexity)]
+    fn try_extended_gcd(
+        self,
+        other: Self,
+    ) -> Result<(Self::Output, Self::Coefficient, Self::Coefficient), IntTraitsError<Self>>;
+
+    /// Returns the inverse of `self` modulo `m`, which is the `x` in `0..m`
+    /// such that `self * x` is congruent to 1 modulo `m`.
+    ///
+    /// Returns `None` if `m` is not positive or if `self` and `m` are not
+    /// coprime, in which case there is no inverse.
+    fn mod_inverse(self, m: Self) -> Option<Self::Output>;
+}
+
+/// Modular arithmetic on integers.
+///
+/// Results are Euclidean residues in `0..|m|`, so they are never negative
+/// even for a negative input or modulus, and intermediate products never
+/// overflow.
+pub trait IntModular: Sized + Copy + fmt::Display {
+    /// The type of the result, which is `Self` for every implementation in
+    /// this crate.
+    type Output;
+
+    /// Returns `self^exp` modulo `m`.
+    ///
+    /// ## Panics
+    /// Panics if `exp` is negative or if `m` is zero.
+    fn pow_mod(self, exp: Self, m: Self) -> Self::Output {
+        match self.try_pow_mod(exp, m) {
+            Ok(r) => r,
+            Err(e) => panic!("cannot take pow_mod: {}", e),
+        }
+    }
+
+    /// Returns `self^exp` modulo `m`, or `None` if `exp` is negative or
+    /// `m` is zero.
+    fn checked_pow_mod(self, exp: Self, m: Self) -> Option<Self::Output> {
+        self.try_pow_mod(exp, m).ok()
+    }
+
+    /// Returns `self^exp` modulo `m`.
+    ///
+    /// ## Errors
+    /// Returns `NegativeInput` if `exp` is negative or `ZeroInput` if `m` is
+    /// zero.
+    fn try_pow_mod(self, exp: Self, m: Self) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns `self + other` modulo `m`.
+    ///
+    /// ## Panics
+    /// Panics if `m` is zero.
+    fn add_mod(self, other: Self, m: Self) -> Self::Output {
+        match self.try_add_mod(other, m) {
+            Ok(r) => r,
+            Err(e) => panic!("cannot take add_mod: {}", e),
+        }
+    }
+
+    /// Returns `self + other` modulo `m`, or `None` if `m` is zero.
+    fn checked_add_mod(self, other: Self, m: Self) -> Option<Self::Output> {
+        self.try_add_mod(other, m).ok()
+    }
+
+    /// Returns `self + other` modulo `m`.
+    ///
+    /// ## Errors
+    /// Returns `ZeroInput` if `m` is zero.
+    fn try_add_mod(self, other: Self, m: Self) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns `self - other` modulo `m`.
+    ///
+    /// ## Panics
+    /// Panics if `m` is zero.
+    fn sub_mod(self, other: Self, m: Self) -> Self::Output {
+        match self.try_sub_mod(other, m) {
+            Ok(r) => r,
+            Err(e) => panic!("cannot take sub_mod: {}", e),
+        }
+    }
+
+    /// Returns `self - other` modulo `m`, or `None` if `m` is zero.
+    fn checked_sub_mod(self, other: Self, m: Self) -> Option<Self::Output> {
+        self.try_sub_mod(other, m).ok()
+    }
+
+    /// Returns `self - other` modulo `m`.
+    ///
+    /// ## Errors
+    /// Returns `ZeroInput` if `m` is zero.
+    fn try_sub_mod(self, other: Self, m: Self) -> Result<Self::Output, IntTraitsError<Self>>;
+
+    /// Returns `self * other` modulo `m`.
+    ///
+    /// ## Panics
+    /// Panics if `m` is zero.
+    fn mul_mod(self, other: Self, m: Self) -> Self::Output {
+        match self.try_mul_mod(other, m) {
+            Ok(r) => r,
+            Err(e) => panic!("cannot take mul_mod: {}", e),
+        }
+    }
+
+    /// Returns `self * other` modulo `m`, or `None` if `m` is zero.
+    fn checked_mul_mod(self, other: Self, m: Self) -> Option<Self::Output> {
+        self.try_mul_mod(other, m).ok()
+    }
+
+    /// Returns `self * other` modulo `m`.
+    ///
+    /// ## Errors
+    /// Returns `ZeroInput` if `m` is zero.
+    fn try_mul_mod(self, other: Self, m: Self) -> Result<Self::Output, IntTraitsError<Self>>;
+}
+
+/// Primality of integers.
+pub trait IntPrime: Sized + Copy {
+    /// Returns whether `n` is prime.
+    ///
+    /// Negative numbers, zero and one are not prime. The test is exact for
+    /// every type up to 64 bits, and for `u128` and `i128` uses the
+    /// Baillie-PSW test, for which no counterexample is known.
+    fn is_prime(self) -> bool;
+}
@@ -0,0 +1,127 @@
+//! Implementations for the `Wrapping` and `Saturating` integer wrappers.
+//!
+//! These defer to the wrapped type and only rewrap the result. A result which
+//! does not fit is reported as an error rather than wrapped or saturated.
+
+use core::num::{Saturating, Wrapping};
+
+use {IntCbrt, IntGcd, IntLog, IntModular, IntPrime, IntRoot, IntSqrt, IntTraitsError};
+
+macro_rules! impl_wrapper_trait {
+    ($w:ident) => {
+        impl<T: IntSqrt<Output = T>> IntSqrt for $w<T> {
+            type Output = $w<T>;
+
+            fn try_sqrt_rem(self) -> Result<($w<T>, $w<T>), IntTraitsError<$w<T>>> {
+                self.0.try_sqrt_rem()
+                    .map(|(root, rem)| ($w(root), $w(rem)))
+                    .map_err(|e| e.map($w))
+            }
+
+            fn try_sqrt_ceil(self) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_sqrt_ceil().map($w).map_err(|e| e.map($w))
+            }
+
+            fn is_square(self) -> bool {
+                self.0.is_square()
+            }
+        }
+
+        impl<T: IntCbrt<Output = T>> IntCbrt for $w<T> {
+            type Output = $w<T>;
+
+            fn try_cbrt_rem(self) -> Result<($w<T>, $w<T>), IntTraitsError<$w<T>>> {
+                self.0.try_cbrt_rem()
+                    .map(|(root, rem)| ($w(root), $w(rem)))
+                    .map_err(|e| e.map($w))
+            }
+
+            fn try_cbrt_ceil(self) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_cbrt_ceil().map($w).map_err(|e| e.map($w))
+            }
+
+            fn is_perfect_cube(self) -> bool {
+                self.0.is_perfect_cube()
+            }
+        }
+
+        impl<T: IntRoot<Output = T>> IntRoot for $w<T> {
+            type Output = $w<T>;
+
+            fn try_nth_root(self, k: u32) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_nth_root(k).map($w).map_err(|e| e.map($w))
+            }
+
+            fn perfect_power(self) -> Option<($w<T>, u32)> {
+                self.0.perfect_power().map(|(root, k)| ($w(root), k))
+            }
+        }
+
+        impl<T: IntLog<Output = T>> IntLog for $w<T> {
+            type Output = $w<T>;
+
+            fn try_log(self, n: u64) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_log(n).map($w).map_err(|e| e.map($w))
+            }
+
+            fn try_log_ceil(self, n: u64) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_log_ceil(n).map($w).map_err(|e| e.map($w))
+            }
+        }
+
+        impl<T: IntGcd<Output = T>> IntGcd for $w<T> {
+            type Output = $w<T>;
+            type Coefficient = $w<T::Coefficient>;
+
+            fn try_gcd(self, other: $w<T>) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_gcd(other.0).map($w).map_err(|e| e.map($w))
+            }
+
+            fn try_lcm(self, other: $w<T>) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_lcm(other.0).map($w).map_err(|e| e.map($w))
+            }
+
+            fn try_extended_gcd(
+                self,
+                other: $w<T>,
+            ) -> Result<($w<T>, $w<T::Coefficient>, $w<T::Coefficient>), IntTraitsError<$w<T>>> {
+                self.0.try_extended_gcd(other.0)
+                    .map(|(g, x, y)| ($w(g), $w(x), $w(y)))
+                    .map_err(|e| e.map($w))
+            }
+
+            fn mod_inverse(self, m: $w<T>) -> Option<$w<T>> {
+                self.0.mod_inverse(m.0).map($w)
+            }
+        }
+
+        impl<T: IntModular<Output = T>> IntModular for $w<T> {
+            type Output = $w<T>;
+
+            fn try_pow_mod(self, exp: $w<T>, m: $w<T>) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_pow_mod(exp.0, m.0).map($w).map_err(|e| e.map($w))
+            }
+
+            fn try_add_mod(self, other: $w<T>, m: $w<T>) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_add_mod(other.0, m.0).map($w).map_err(|e| e.map($w))
+            }
+
+            fn try_sub_mod(self, other: $w<T>, m: $w<T>) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_sub_mod(other.0, m.0).map($w).map_err(|e| e.map($w))
+            }
+
+            fn try_mul_mod(self, other: $w<T>, m: $w<T>) -> Result<$w<T>, IntTraitsError<$w<T>>> {
+                self.0.try_mul_mod(other.0, m.0).map($w).map_err(|e| e.map($w))
+            }
+        }
+
+        impl<T: IntPrime> IntPrime for $w<T> {
+            fn is_prime(self) -> bool {
+                self.0.is_prime()
+            }
+        }
+    };
+}
+
+impl_wrapper_trait!(Wrapping);
+impl_wrapper_trait!(Saturating);